@@ -31,6 +31,22 @@
 //! assert_eq!(query.as_string(), "INSERT INTO users(name) VALUES('george')");
 //! ```
 //! <br>
+//! Every query type has a single lifetime `'a`, the lifetime of the table names, columns
+//! and values it borrows. Text built at runtime can be handed over owned as a [`Value`],
+//! so borrowed and owned strings can be mixed in one query:
+//!
+//! ```
+//! use query_builder::{SelectQuery, WhereClause, Value};
+//!
+//! fn by_city<'a>(table: &'a str, city: String) -> SelectQuery<'a> {
+//!     let mut query = SelectQuery::select(&["*"]).from(table);
+//!     query.whre.push(WhereClause::new("city", city.into()));
+//!     query
+//! }
+//!
+//! assert_eq!(by_city("users", "berlin".to_string()).as_string(), "SELECT * FROM users WHERE city = 'berlin'");
+//! ```
+//! <br>
 //! More detailed explanations and examples can be found at the corresponding sections
 //! to the structs and enums
 //!
@@ -39,13 +55,38 @@
 //! [`Value`]: ./enum.Value.html
 
 
+#[cfg(feature = "tokio-postgres")]
+extern crate bytes;
+#[cfg(feature = "tokio-postgres")]
+extern crate tokio_postgres;
+#[cfg(feature = "rusqlite")]
+extern crate rusqlite;
+
+#[cfg(feature = "tokio-postgres")]
+pub mod postgres;
+#[cfg(feature = "rusqlite")]
+pub mod sqlite;
+
 // std imports
-use std::collections::BTreeMap;
-use std::fmt::{Display, Formatter, Result as FormatResult};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FormatResult, Write};
+use std::ops::Index;
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 /// Enum representing common SQL-datatypes
-pub enum Value<'c> {
-    Varchar(&'c str),
+pub enum Value<'a> {
+    /// The SQL `NULL`
+    Null,
+    Varchar(&'a str),
+    /// Like [`Value::Varchar`], but the string can be borrowed or owned
+    ///
+    /// This allows strings built at runtime to be used in a query without having
+    /// to keep them alive somewhere else. Owned strings convert into this with `into()`.
+    ///
+    /// [`Value::Varchar`]: ./enum.Value.html#variant.Varchar
+    Text(Cow<'a, str>),
     Bool(bool),
     Tinyint(i8),
     UnsignedTinyint(u8),
@@ -55,10 +96,42 @@ pub enum Value<'c> {
     UnsignedInt(u32),
     Bigint(i64),
     UnsignedBigint(u64),
+    /// A floating point number, see [`Float`] for how it is written
+    ///
+    /// [`Float`]: ./struct.Float.html
+    Double(Float),
+    /// A time interval such as `'7 days'`, rendered as `INTERVAL '7 days'`
+    Interval(&'a str),
+    /// A parenthesized list of values, e.g. for use with [`Operator::In`]
+    ///
+    /// [`Operator::In`]: ./enum.Operator.html#variant.In
+    List(Vec<Value<'a>>),
+    /// A reference to another column, rendered as is and never bound as a parameter
+    Column(&'a str),
+    /// The `DEFAULT` keyword, which stands for the default value of the column it is assigned to
+    ///
+    /// Like [`Value::Column`] it is never bound as a parameter.
+    ///
+    /// [`Value::Column`]: ./enum.Value.html#variant.Column
+    Default,
+    /// A Postgres array, rendered as `ARRAY[1, 2, 3]`
+    ///
+    /// Only supported by [`Dialect::Postgres`], nested arrays are not supported.
+    ///
+    /// [`Dialect::Postgres`]: ./enum.Dialect.html#variant.Postgres
+    Array(Vec<Value<'a>>),
+    /// The result of a [`SelectQuery`], rendered in parentheses
+    ///
+    /// [`SelectQuery`]: ./struct.SelectQuery.html
+    Subquery(Box<SelectQuery<'a>>),
+    /// A `CASE` expression built with [`CaseExpr`], its values are bound where they appear
+    ///
+    /// [`CaseExpr`]: ./struct.CaseExpr.html
+    Case(Box<CaseExpr<'a>>),
 }
 
 #[allow(unused_assignments)]
-impl<'c> Value<'c> {
+impl<'a> Value<'a> {
     /// Convert the Value to a [`String`]
     /// 
     /// ## Example
@@ -77,13 +150,27 @@ impl<'c> Value<'c> {
     /// // applies to all numeric Values
     /// let v = Value::Int(42);
     /// assert_eq!(v.as_string(), "42");
+    ///
+    /// // Intervals are prefixed with the INTERVAL keyword
+    /// let v = Value::Interval("7 days");
+    /// assert_eq!(v.as_string(), "INTERVAL '7 days'");
+    ///
+    /// // Lists are comma separated and parenthesized
+    /// let v = Value::List(vec![Value::Int(1), Value::Int(2)]);
+    /// assert_eq!(v.as_string(), "(1, 2)");
+    ///
+    /// // Arrays use the Postgres array constructor
+    /// let v = Value::Array(vec![Value::Varchar("a"), Value::Varchar("b")]);
+    /// assert_eq!(v.as_string(), "ARRAY['a', 'b']");
     /// ```
     /// 
     /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
     /// 
     pub fn as_string(&self) -> String {
         match *self {
+            Value::Null => "NULL".to_string(),
             Value::Varchar(v) => format!("'{}'", v),
+            Value::Text(ref t) => format!("'{}'", t),
             Value::Bool(b) => if b {
                 "TRUE".to_string()
             } else {
@@ -97,741 +184,7740 @@ impl<'c> Value<'c> {
             Value::UnsignedInt(ui) => format!("{}", ui),
             Value::Bigint(bi) => format!("{}", bi),
             Value::UnsignedBigint(ubi) => format!("{}", ubi),
+            Value::Double(d) => d.to_string(),
+            Value::Interval(i) => format!("INTERVAL '{}'", i),
+            Value::Column(c) => c.to_string(),
+            Value::Default => "DEFAULT".to_string(),
+            Value::List(ref l) => {
+                let items: Vec<String> = l.iter().map(|v| v.as_string()).collect();
+                format!("({})", items.join(", "))
+            },
+            Value::Array(ref a) => {
+                let items: Vec<String> = a.iter().map(|v| v.as_string()).collect();
+                format!("ARRAY[{}]", items.join(", "))
+            },
+            Value::Subquery(ref q) => format!("({})", q.as_string()),
+            Value::Case(ref c) => c.as_string(),
+        }
+    }
+
+    /// Returns a `LIKE` pattern matching all text that contains `s`
+    ///
+    /// A `%`, `_` or `\` in `s` is escaped with a backslash, so it is matched literally.
+    ///
+    /// Only MySQL and Postgres use the backslash as escape character by default. SQLite
+    /// and SQL Server do not, there the pattern silently matches nothing unless the clause
+    /// is given [`WhereClause::escape`]`('\\')`. [`WhereClause::contains`] does this already.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{WhereClause, Operator, Value};
+    ///
+    /// let clause = WhereClause::with_operator("title", Operator::Like, Value::like_contains("50%")).escape('\\');
+    /// assert_eq!(clause.as_string_no_cond(), "title LIKE '%50\\%%' ESCAPE '\\'");
+    /// ```
+    ///
+    /// [`WhereClause::escape`]: ./struct.WhereClause.html#method.escape
+    /// [`WhereClause::contains`]: ./struct.WhereClause.html#method.contains
+    pub fn like_contains(s: &str) -> Value<'a> {
+        Value::Text(Cow::Owned(format!("%{}%", escape_like(s))))
+    }
+
+    /// Returns a `LIKE` pattern matching all text that starts with `s`, see [`like_contains`]
+    ///
+    /// Like there, the clause still needs [`WhereClause::escape`]`('\\')`, or use
+    /// [`WhereClause::starts_with`].
+    ///
+    /// [`like_contains`]: #method.like_contains
+    /// [`WhereClause::escape`]: ./struct.WhereClause.html#method.escape
+    /// [`WhereClause::starts_with`]: ./struct.WhereClause.html#method.starts_with
+    pub fn like_starts_with(s: &str) -> Value<'a> {
+        Value::Text(Cow::Owned(format!("{}%", escape_like(s))))
+    }
+
+    /// Returns a `LIKE` pattern matching all text that ends with `s`, see [`like_contains`]
+    ///
+    /// Like there, the clause still needs [`WhereClause::escape`]`('\\')`, or use
+    /// [`WhereClause::ends_with`].
+    ///
+    /// [`like_contains`]: #method.like_contains
+    /// [`WhereClause::escape`]: ./struct.WhereClause.html#method.escape
+    /// [`WhereClause::ends_with`]: ./struct.WhereClause.html#method.ends_with
+    pub fn like_ends_with(s: &str) -> Value<'a> {
+        Value::Text(Cow::Owned(format!("%{}", escape_like(s))))
+    }
+}
+
+/// Escapes the wildcards of a `LIKE` pattern and the backslash escaping them
+fn escape_like(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c == '%' || c == '_' || c == '\\' {
+            res.push('\\');
         }
+        res.push(c);
     }
+    res
 }
 
-impl<'c> Display for Value<'c> {
+impl<'a> Display for Value<'a> {
     fn fmt(&self, f: &mut Formatter) -> FormatResult {
         write!(f, "{}", self.as_string())
     }
 }
 
-#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
-/// Enum representing the ways to combine conditional parts of a query
-pub enum Condition {
-    And,
-    Or
+impl<'a> From<&'a str> for Value<'a> {
+    fn from(v: &'a str) -> Value<'a> {
+        Value::Varchar(v)
+    }
 }
 
-impl Display for  Condition {
-    fn fmt(&self, f: &mut Formatter) -> FormatResult {
-        match *self {
-            Condition::And  => write!(f, "AND"),
-            Condition::Or   => write!(f, "OR"),
-        }
+impl<'a> From<String> for Value<'a> {
+    fn from(v: String) -> Value<'a> {
+        Value::Text(Cow::Owned(v))
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
-/// Representing the way to Format the ORDER BY clause of some queries
-pub enum OrderBy<'b> {
-    Row(&'b str),
-    Expression(&'b str),
+impl<'a> From<Cow<'a, str>> for Value<'a> {
+    fn from(v: Cow<'a, str>) -> Value<'a> {
+        Value::Text(v)
+    }
 }
 
-impl<'b> OrderBy<'b> {
-    pub fn as_string(&self) -> String {
-        match *self {
-            OrderBy::Row(r) => format!("ORDER BY {}", r),
-            OrderBy::Expression(e)  => format!("ORDER BY {}", e),
-        }
+impl<'a> From<bool> for Value<'a> {
+    fn from(v: bool) -> Value<'a> {
+        Value::Bool(v)
     }
 }
 
-impl<'b> Display for OrderBy<'b> {
-    fn fmt(&self, f: &mut Formatter) -> FormatResult {
-        write!(f, "{}", self.as_string())
+impl<'a> From<i8> for Value<'a> {
+    fn from(v: i8) -> Value<'a> {
+        Value::Tinyint(v)
     }
 }
 
-#[derive(Debug)]
-/// Struct representing an WHERE-Clause
-/// 
-/// 
-pub struct WhereClause<'a, 'b> {
-    tbl: &'a str,
-    cond: Value<'b>,
-    how: Condition,
+impl<'a> From<u8> for Value<'a> {
+    fn from(v: u8) -> Value<'a> {
+        Value::UnsignedTinyint(v)
+    }
 }
 
-impl<'a, 'b> WhereClause<'a, 'b> {
-    /// Creates a new WHERE-clause
-    /// 
-    /// If the Value of `how` is none when initializing the clause, [`Condition::And`]
-    /// is assumed and used for the clause.
-    /// 
-    /// 
-    /// 
-    /// *Note:* If the [`WhereClause`] is the first one to be inserted in the string of an query, 
-    /// the condition will be left out.
-    /// 
-    /// [`WhereClause`]: ./struct.WhereClause.html
-    /// [`Condition::And`]: ./enum.Condition.html#variant.And
-    ///  
-    pub fn new(table: &'a str, cond: Value<'b>, how: Option<Condition>) -> WhereClause<'a, 'b> {
-        if let Some(c) = how {
-            WhereClause {
-                tbl: table,
-                cond: cond,
-                how: c
-            }
-        } else {
-            WhereClause {
-                tbl: table,
-                cond: cond,
-                how: Condition::And,
-            }
-        }
+impl<'a> From<i16> for Value<'a> {
+    fn from(v: i16) -> Value<'a> {
+        Value::Smallint(v)
     }
+}
 
-    /// Returns a [`String`] representing the [`WhereClause`] with it's condition part
-    /// The returned [`String`] is also the default representation returned when calling the 
-    /// [`Display`] trait functions on this struct.
-    /// 
-    /// ## Example
-    /// 
-    /// ```
-    /// use query_builder::{WhereClause, Condition, Value};
-    /// 
-    /// let wclause = WhereClause::new("user", Value::Varchar("gerald"), Some(Condition::Or));
-    /// 
-    /// assert_eq!(wclause.as_string(), "OR user = 'gerald'")
-    /// ```
-    /// 
-    /// [`WhereClause`]: ./struct.WhereClause.html
-    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
-    /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
-    pub fn as_string(&self) -> String {
-        format!("{} {} = {}", self.how, self.tbl, self.cond)
+impl<'a> From<u16> for Value<'a> {
+    fn from(v: u16) -> Value<'a> {
+        Value::UnsignedSmallint(v)
     }
+}
 
-    /// Returns a [`String`] representing the [`WhereClause`] without it's condition part
-    /// but with the `WHERE` phrase in the beginning
-    /// 
-    /// ## Example
-    /// 
-    /// ```
-    /// use query_builder::{WhereClause, Value};
-    /// 
-    /// let clause = WhereClause::new("user", Value::Varchar("thomas"), None);
-    /// 
-    /// assert_eq!(clause.as_string_no_cond_with_prefix(), "WHERE user = 'thomas'")
-    /// ```
-    /// 
-    /// [`WhereClause`]: ./struct.WhereClause.html
-    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
-    pub fn as_string_no_cond_with_prefix(&self) -> String {
-        format!("WHERE {} = {}", self.tbl, self.cond)
+impl<'a> From<i32> for Value<'a> {
+    fn from(v: i32) -> Value<'a> {
+        Value::Int(v)
     }
+}
 
-    /// Returns a [`String`] representing the [`WhereClause`] without `WHERE` prefix and 
-    /// without it's conditional parts
-    /// 
-    /// ## Example
-    /// 
-    /// ```
-    /// use query_builder::{WhereClause, Value};
-    /// 
-    /// let clause = WhereClause::new("user", Value::Varchar("jeanny"), None);
-    /// 
-    /// assert_eq!(clause.as_string_no_cond(), "user = 'jeanny'")
-    /// ```
-    /// 
-    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
-    /// [`WhereClause`]: ./struct.WhereClause.html
-    pub fn as_string_no_cond(&self) -> String {
-        format!("{} = {}", self.tbl, self.cond)
+impl<'a> From<u32> for Value<'a> {
+    fn from(v: u32) -> Value<'a> {
+        Value::UnsignedInt(v)
     }
+}
 
+impl<'a> From<i64> for Value<'a> {
+    fn from(v: i64) -> Value<'a> {
+        Value::Bigint(v)
+    }
 }
 
-impl<'a, 'b> Display for WhereClause<'a, 'b> {
-    fn fmt(&self, f: &mut Formatter) -> FormatResult {
-        write!(f, "{}", self.as_string())
+impl<'a> From<u64> for Value<'a> {
+    fn from(v: u64) -> Value<'a> {
+        Value::UnsignedBigint(v)
     }
-} 
+}
 
+impl<'a> From<f32> for Value<'a> {
+    fn from(v: f32) -> Value<'a> {
+        Value::Double(Float(f64::from(v)))
+    }
+}
 
+impl<'a> From<f64> for Value<'a> {
+    fn from(v: f64) -> Value<'a> {
+        Value::Double(Float(v))
+    }
+}
 
-#[derive(Debug)]
-/// Struct representing a SQL-INSERT Query
-/// A simple query to select everything from a table can be created like this:
-/// 
-/// ## Example 
-/// 
-/// ```
-/// use query_builder::SelectQuery;
+#[derive(Debug, Clone, Copy)]
+/// Struct wrapping a `f64` so it can be used as a [`Value`]
 ///
-/// // create the query
-/// let query = SelectQuery::select(&["*"]).from("users");
+/// Finite numbers are always written in plain decimal notation with at least one
+/// decimal place, e.g. `1e20` is written as `100000000000000000000.0`, since some
+/// databases reject exponents in literals. `NaN` and the infinities are written as
+/// the quoted strings `'NaN'`, `'Infinity'` and `'-Infinity'`, which Postgres accepts.
+///
+/// Floats are compared by their total order, so a `NaN` equals itself.
+///
+/// ## Example
 ///
-/// // make sure it looks like you would expect it to look
-/// assert_eq!(query.as_string(), "SELECT * FROM users");
 /// ```
-pub struct SelectQuery<'a, 'c> {
-    select: Vec<&'a str>,
-    from: &'a str,
-    pub whre: Vec<WhereClause<'a, 'c>>,
-    limit: Option<usize>,
-    order_by: Option<OrderBy<'c>>
-}
+/// use query_builder::{Float, Value};
+///
+/// assert_eq!(Value::Double(Float(1e20)).as_string(), "100000000000000000000.0");
+/// assert_eq!(Value::from(0.25).as_string(), "0.25");
+/// ```
+///
+/// [`Value`]: ./enum.Value.html
+pub struct Float(pub f64);
 
-impl<'a, 'c> Display for SelectQuery<'a, 'c> {
+impl Display for Float {
     fn fmt(&self, f: &mut Formatter) -> FormatResult {
-        write!(f, "{}", self.as_string())
+        if self.0.is_nan() {
+            write!(f, "'NaN'")
+        } else if self.0.is_infinite() {
+            write!(f, "'{}Infinity'", if self.0 < 0.0 { "-" } else { "" })
+        } else if self.0.fract() == 0.0 {
+            /* `Display` never uses exponents, but drops the decimal place of whole numbers */
+            write!(f, "{}.0", self.0)
+        } else {
+            write!(f, "{}", self.0)
+        }
     }
 }
 
+impl PartialEq for Float {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
 
-#[allow(unused_assignments)]
-impl<'a, 'c> SelectQuery<'a, 'c> {
-    /// Creates a new [`SelectQuery`] that selects data from the row/s `rows`
-    ///
-    /// [`SelectQuery`]: ./struct.SelectQuery.html
-    pub fn select(rows: &[&'a str]) -> SelectQuery<'a, 'c> {
-        SelectQuery {
-            select: rows.to_vec(),
-            from: "",
-            whre: Vec::new(),
-            limit: None,
-            order_by: None,
-        }
+impl Eq for Float {}
+
+impl PartialOrd for Float {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Float {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
     }
+}
 
-    /// Sets the table to select from to the value of `t`
-    /// ## Example
-    /// 
-    /// ```
-    /// use query_builder::SelectQuery;
-    ///
-    /// let q = SelectQuery::select(&["user"]).from("users");
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Default)]
+/// Struct representing a `CASE WHEN ... THEN ... ELSE ... END` expression
+///
+/// Wrapped in a [`Value::Case`] it can be used wherever a value is expected, e.g. as
+/// the new value of a column in an [`UpdateQuery`].
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{CaseExpr, WhereClause, Value};
+///
+/// let case = CaseExpr::new()
+///     .when(WhereClause::new("score", Value::Int(100)), Value::Varchar("perfect"))
+///     .otherwise(Value::Column("status"));
+///
+/// assert_eq!(case.as_string(), "CASE WHEN score = 100 THEN 'perfect' ELSE status END");
+/// ```
+///
+/// [`Value::Case`]: ./enum.Value.html#variant.Case
+/// [`UpdateQuery`]: ./struct.UpdateQuery.html
+pub struct CaseExpr<'a> {
+    whens: Vec<(WhereClause<'a>, Value<'a>)>,
+    otherwise: Option<Value<'a>>,
+}
+
+impl<'a> CaseExpr<'a> {
+    /// Creates an empty `CASE` expression
+    pub fn new() -> Self {
+        CaseExpr::default()
+    }
+
+    /// Adds a `WHEN cond THEN value` branch, branches are checked in the order they are added
     ///
-    /// assert_eq!(q.as_string(), "SELECT user FROM users")
-    /// ```
-    pub fn from(mut self, t: &'a str) -> Self {
-        self.from = t;
+    pub fn when(mut self, cond: WhereClause<'a>, value: Value<'a>) -> Self {
+        self.whens.push((cond, value));
         self
     }
 
-    /// Sets the limit value of the Query to the value of `l`
-    /// ## Example
-    /// 
-    /// ```
-    /// use query_builder::SelectQuery;
-    ///
-    /// let mut q = SelectQuery::select(&["user"]).from("users");
-    /// q.limit(12);
-    ///
-    /// assert_eq!(q.as_string(), "SELECT user FROM users LIMIT 12")
-    /// ```
-    pub fn limit(&mut self, l: usize) {
-        self.limit = Some(l);
+    /// Sets the value of the `ELSE` branch, without it the expression is `NULL` if no branch matches
+    pub fn otherwise(mut self, value: Value<'a>) -> Self {
+        self.otherwise = Some(value);
+        self
     }
 
-    /// Return whether or not the [`SelectQuery`] has a limit
-    /// ## Example
-    /// 
-    /// ```
-    /// use query_builder::SelectQuery;
+    /// Returns the expression as a [`String`] with all values written inline
     ///
-    /// let mut q = SelectQuery::select(&["user"]).from("users");
-    /// q.limit(12);
-    /// 
-    /// assert!(q.has_limit());
-    /// 
-    /// q.clear_limit();
-    /// assert!(!q.has_limit());
-    /// ```
-    /// [`SelectQuery`]: ./struct.SelectQuery.html
-    pub fn has_limit(&self) -> bool {
-        if let Some(_) = self.limit {
-            return true;
-        }
-
-        false
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn as_string(&self) -> String {
+        self.render("", &mut Context::literal(Dialect::Generic))
     }
 
-    /// Returns the value of the Limit of the [`SelectQuery`] if there is one
-    /// ## Example
-    /// 
-    /// ```
-    /// use query_builder::SelectQuery;
-    /// 
-    /// let mut q = SelectQuery::select(&["user"]).from("users");
-    /// assert_eq!(q.get_limit(), None);
-    /// 
-    /// q.limit(12);
-    /// assert_eq!(q.get_limit(), Some(12));
-    /// ```
-    /// [`SelectQuery`]: ./struct.SelectQuery.html
-    pub fn get_limit(&self) -> Option<usize> {
-        self.limit
+    /// Renders the expression for `column`, passing the values through `ctx`
+    fn render(&self, column: &str, ctx: &mut Context<'a>) -> String {
+        let mut res = ctx.kw("CASE");
+        for (cond, value) in &self.whens {
+            let cond = cond.render(ctx);
+            res.push_str(&ctx.case().format(" WHEN {} THEN {}", &[&cond, &ctx.value(column, value)]));
+        }
+        if let Some(ref v) = self.otherwise {
+            res.push_str(&ctx.case().format(" ELSE {}", &[&ctx.value(column, v)]));
+        }
+        res.push_str(&ctx.kw(" END"));
+        res
     }
 
-    /// Removes the limit from the query
-    /// ## Example
-    /// 
-    /// ```
-    /// use query_builder::SelectQuery;
-    ///
-    /// let mut q = SelectQuery::select(&["user"]).from("users");
-    /// 
-    /// // set the limit
-    /// q.limit(42);
-    /// assert_eq!(q.as_string(), "SELECT user FROM users LIMIT 42");
-    ///
-    /// // clear limit
-    /// q.clear_limit();
-    /// 
-    /// assert_eq!(q.as_string(), "SELECT user FROM users");
-    /// ```
-    pub fn clear_limit(&mut self) {
-        self.limit = None;
+    /// Returns the values of the expression in the order they appear
+    fn collect_values<'v>(&'v self, values: &mut Vec<&'v Value<'a>>) {
+        for (cond, value) in &self.whens {
+            collect_clauses(::std::slice::from_ref(cond), values);
+            collect_value(value, values);
+        }
+        if let Some(ref v) = self.otherwise {
+            collect_value(v, values);
+        }
     }
+}
 
-    /// Adds a ORDER BY clause to the query
-    pub fn order_by(&mut self, ob: OrderBy<'c>) {
-        self.order_by = Some(ob);
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
+/// Enum representing the ways to combine conditional parts of a query
+///
+/// The [`WhereClause`]s of a query are combined from left to right, the way they are
+/// added. Since SQL binds `AND` tighter than `OR`, everything before a clause is put in
+/// parentheses whenever its combiner differs from the one before.
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{SelectQuery, WhereClause, Value};
+///
+/// let mut q = SelectQuery::select(&["*"]).from("t");
+/// q.whre.push(WhereClause::new("a", Value::Int(1)));
+/// q.whre.push_or(WhereClause::new("b", Value::Int(2)));
+/// q.whre.push(WhereClause::new("c", Value::Int(3)));
+///
+/// assert_eq!(q.as_string(), "SELECT * FROM t WHERE (a = 1 OR b = 2) AND c = 3");
+/// ```
+///
+/// [`WhereClause`]: ./struct.WhereClause.html
+pub enum Condition {
+    And,
+    Or
+}
+
+impl Display for  Condition {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            Condition::And  => write!(f, "AND"),
+            Condition::Or   => write!(f, "OR"),
+        }
     }
-    /// Creates the string representation of the query
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the operators comparing a column with a value in a [`WhereClause`]
+///
+/// [`WhereClause`]: ./struct.WhereClause.html
+pub enum Operator {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    Like,
+    NotLike,
+    /// Expects a [`Value::List`] to compare against
+    ///
+    /// [`Value::List`]: ./enum.Value.html#variant.List
+    In,
+    /// Expects a [`Value::List`] to compare against
+    ///
+    /// [`Value::List`]: ./enum.Value.html#variant.List
+    NotIn,
+    /// Used with [`Value::Null`], the value is never bound as a parameter
+    ///
+    /// [`Value::Null`]: ./enum.Value.html#variant.Null
+    Is,
+    /// Used with [`Value::Null`], the value is never bound as a parameter
+    ///
+    /// [`Value::Null`]: ./enum.Value.html#variant.Null
+    IsNot,
+    /// Like [`Operator::NotEq`], but treats `NULL` as a value of its own, so that
+    /// `NULL IS DISTINCT FROM 1` is true instead of `NULL`
+    ///
+    /// MySQL has no such operator and gets `NOT (a <=> b)` instead.
+    ///
+    /// [`Operator::NotEq`]: #variant.NotEq
+    DistinctFrom,
+    /// Like [`Operator::Eq`], but treats `NULL` as a value of its own, so that
+    /// `NULL IS NOT DISTINCT FROM NULL` is true
+    ///
+    /// MySQL has no such operator and gets `a <=> b` instead.
+    ///
+    /// [`Operator::Eq`]: #variant.Eq
+    NotDistinctFrom,
+}
+
+impl Operator {
+    /// Returns the operator matching exactly the rows this one does not match
+    ///
     /// ## Example
-    /// 
-    /// ```
-    /// use query_builder::SelectQuery;
     ///
-    /// let mut q = SelectQuery::select(&["*"]).from("users");
+    /// ```
+    /// use query_builder::Operator;
     ///
-    /// assert_eq!(q.as_string(), "SELECT * FROM users")
+    /// assert_eq!(Operator::Lt.negate(), Operator::GtEq);
+    /// assert_eq!(Operator::In.negate(), Operator::NotIn);
     /// ```
-    pub fn as_string(&self) -> String {
-        let mut res: String = String::new();
-        if !self.select.is_empty() {
-            res = format!("SELECT {}", self.select[0]);
-            if self.select.len() > 1 {
-                for s in self.select[1..].iter() {
-                    res = format!("{}, {}", res, s);
-                }
-            }
+    pub fn negate(&self) -> Operator {
+        match *self {
+            Operator::Eq        => Operator::NotEq,
+            Operator::NotEq     => Operator::Eq,
+            Operator::Lt        => Operator::GtEq,
+            Operator::LtEq      => Operator::Gt,
+            Operator::Gt        => Operator::LtEq,
+            Operator::GtEq      => Operator::Lt,
+            Operator::Like      => Operator::NotLike,
+            Operator::NotLike   => Operator::Like,
+            Operator::In        => Operator::NotIn,
+            Operator::NotIn     => Operator::In,
+            Operator::Is        => Operator::IsNot,
+            Operator::IsNot     => Operator::Is,
+            Operator::DistinctFrom      => Operator::NotDistinctFrom,
+            Operator::NotDistinctFrom   => Operator::DistinctFrom,
         }
+    }
+}
 
-        if self.from.len() > 1 {
-            res = format!("{} FROM {}", res, self.from);
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            Operator::Eq        => write!(f, "="),
+            Operator::NotEq     => write!(f, "!="),
+            Operator::Lt        => write!(f, "<"),
+            Operator::LtEq      => write!(f, "<="),
+            Operator::Gt        => write!(f, ">"),
+            Operator::GtEq      => write!(f, ">="),
+            Operator::Like      => write!(f, "LIKE"),
+            Operator::NotLike   => write!(f, "NOT LIKE"),
+            Operator::In        => write!(f, "IN"),
+            Operator::NotIn     => write!(f, "NOT IN"),
+            Operator::Is        => write!(f, "IS"),
+            Operator::IsNot     => write!(f, "IS NOT"),
+            Operator::DistinctFrom      => write!(f, "IS DISTINCT FROM"),
+            Operator::NotDistinctFrom   => write!(f, "IS NOT DISTINCT FROM"),
         }
+    }
+}
 
-        if !self.whre.is_empty() {
-            let c = &self.whre[0];
-            res = format!("{} {}", res, c.as_string_no_cond_with_prefix());
-            for clause in &self.whre[1..] {
-                res = format!("{} {}", res, clause);
-            }
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the quantifiers comparing a column with every value of a
+/// subquery or an array
+pub enum Quantifier {
+    /// The comparison has to hold for at least one of the values
+    Any,
+    /// The comparison has to hold for all of the values
+    All,
+}
+
+impl Display for Quantifier {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            Quantifier::Any => write!(f, "ANY"),
+            Quantifier::All => write!(f, "ALL"),
         }
+    }
+}
 
-        if let Some(l) = self.limit {
-            res = format!("{} LIMIT {}", res, l);
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the SQL dialects a query can be rendered for
+///
+/// Queries use [`Dialect::Generic`] unless told otherwise, which only allows features
+/// that are not specific to a single database.
+///
+/// [`Dialect::Generic`]: ./enum.Dialect.html#variant.Generic
+pub enum Dialect {
+    #[default]
+    Generic,
+    MySql,
+    Postgres,
+    Sqlite,
+    SqlServer,
+}
+
+impl Display for Dialect {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            Dialect::Generic    => write!(f, "generic SQL"),
+            Dialect::MySql      => write!(f, "MySQL"),
+            Dialect::Postgres   => write!(f, "PostgreSQL"),
+            Dialect::Sqlite     => write!(f, "SQLite"),
+            Dialect::SqlServer  => write!(f, "SQL Server"),
+        }
+    }
+}
+
+/// Trait for turning the values of a query into SQL literals
+///
+/// Every [`Dialect`] implements it, which is what queries use by default. Queries
+/// can be given a renderer of their own to change how certain values are written,
+/// the values of placeholder queries are bound and never rendered. Renderers have
+/// to be `Sync`, as queries are shared with database drivers.
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{ValueRenderer, Value, Dialect, InsertQuery};
+///
+/// #[derive(Debug)]
+/// struct YesNo;
+///
+/// impl ValueRenderer for YesNo {
+///     fn render(&self, v: &Value) -> String {
+///         match *v {
+///             Value::Bool(b) => if b { "'Y'".to_string() } else { "'N'".to_string() },
+///             _ => Dialect::Generic.render(v),
+///         }
+///     }
+/// }
+///
+/// let mut query = InsertQuery::into("users");
+/// query.values.insert("active", Value::Bool(true));
+/// query.renderer(&YesNo);
+///
+/// assert_eq!(query.as_string(), "INSERT INTO users(active) VALUES('Y')");
+/// ```
+///
+/// [`Dialect`]: ./enum.Dialect.html
+pub trait ValueRenderer: Debug + Sync {
+    /// Returns the SQL literal of `v`
+    fn render(&self, v: &Value) -> String;
+}
+
+impl ValueRenderer for Dialect {
+    /// Renders `v` like [`Value::as_string`], except for booleans on SQL Server, which
+    /// has no boolean literals and gets `1` or `0` instead, and text on MySQL, which reads
+    /// a backslash in a literal as an escape and gets every backslash doubled
+    ///
+    /// [`Value::as_string`]: ./enum.Value.html#method.as_string
+    fn render(&self, v: &Value) -> String {
+        match (*self, v) {
+            (Dialect::SqlServer, &Value::Bool(b)) => if b { "1".to_string() } else { "0".to_string() },
+            (Dialect::MySql, &Value::Varchar(t)) => format!("'{}'", t.replace('\\', "\\\\")),
+            (Dialect::MySql, Value::Text(t)) => format!("'{}'", t.replace('\\', "\\\\")),
+            _ => v.as_string(),
         }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing how the rows of an insert with more than one row are laid out
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{InsertQuery, Value, RowLayout};
+/// use std::collections::BTreeMap;
+///
+/// let mut query = InsertQuery::into("users");
+/// query.values.insert("name", Value::Varchar("anna"));
+///
+/// let mut row = BTreeMap::new();
+/// row.insert("name", Value::Varchar("bert"));
+/// query.add_row(row);
+///
+/// assert_eq!(query.as_string(), "INSERT INTO users(name) VALUES('anna'), ('bert')");
+///
+/// query.row_layout(RowLayout::Expanded);
+/// assert_eq!(query.as_string(), "INSERT INTO users(name) VALUES\n    ('anna'),\n    ('bert')");
+/// ```
+pub enum RowLayout {
+    /// All rows on the same line
+    #[default]
+    Compact,
+    /// Every row on an indented line of its own, which keeps bulk inserts reviewable
+    Expanded,
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the case the keywords of a query are written in
+///
+/// Only the keywords the query writes itself are affected. Identifiers, literals,
+/// comments and raw SQL given to the query are written as they are, even where they
+/// spell a keyword. Subqueries are written in the case of the query they are part of.
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{SelectQuery, Value, WhereClause, KeywordCase};
+///
+/// let mut query = SelectQuery::select(&["name"]).from("users");
+/// query.whre.push(WhereClause::new("status", Value::Varchar("NOT SET")));
+/// query.keyword_case(KeywordCase::Lower);
+///
+/// assert_eq!(query.as_string(), "select name from users where status = 'NOT SET'");
+/// ```
+pub enum KeywordCase {
+    #[default]
+    Upper,
+    Lower,
+}
 
-        if let Some(ref ob) = self.order_by {
-            res = format!("{} {}", res, ob);
+impl KeywordCase {
+    /// Returns `keywords`, a piece of SQL without identifiers or literals, in this case
+    fn write<T: Display>(self, keywords: T) -> String {
+        match self {
+            KeywordCase::Upper => keywords.to_string(),
+            KeywordCase::Lower => keywords.to_string().to_lowercase(),
         }
+    }
 
+    /// Works like `format!` with plain `{}` placeholders, writing `template` in this case
+    /// and the arguments as they are
+    fn format(self, template: &str, args: &[&dyn Display]) -> String {
+        let mut res = String::with_capacity(template.len());
+        let mut args = args.iter();
+        for (i, part) in template.split("{}").enumerate() {
+            if i > 0 {
+                res.push_str(&args.next().expect("an argument for every placeholder").to_string());
+            }
+            res.push_str(&self.write(part));
+        }
         res
     }
 }
 
-
-#[derive(Debug)]
-/// Struct representing an SQL Insert Statement
-pub struct InsertQuery<'a> {
-    into: &'a str,
-    pub values: BTreeMap<&'a str, Value<'a>>,
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// Enum representing the reasons a query can not be turned into valid SQL
+pub enum QueryError {
+    /// The query uses `feature`, which `dialect` does not support
+    Unsupported {
+        feature: &'static str,
+        dialect: Dialect,
+    },
+    /// Page numbers start at 1, page 0 was requested
+    PageZero,
+    /// The offset of the requested page does not fit into a `usize`
+    PageOutOfRange {
+        page: usize,
+        per_page: usize,
+    },
+    /// An `IN` list without any values was requested, which is not valid SQL
+    EmptyList,
+    /// The column is given more than once, ignoring case
+    DuplicateColumn {
+        column: String,
+    },
+    /// The text written into the column is longer than the length declared for it
+    TooLong {
+        column: String,
+        max_length: usize,
+    },
+    /// A `UNION` of no queries at all was requested
+    EmptyUnion,
+    /// An aggregate of distinct values was requested without any column, e.g. `COUNT(DISTINCT *)`
+    EmptyColumns,
+    /// The query has a limit or an offset, which the dialect only supports for ordered queries
+    MissingOrderBy,
+    /// A query selecting distinct rows is ordered by a column it does not select
+    OrderNotSelected {
+        column: String,
+    },
+    /// The query has `count` conditions, more than the `max` it was limited to
+    TooManyConditions {
+        count: usize,
+        max: usize,
+    },
+    /// The query uses a column it was not expected to
+    UnexpectedColumn {
+        column: String,
+    },
+    /// The column is assigned two different values
+    ConflictingAssignment {
+        column: String,
+    },
+    /// Queries on the tables `left` and `right` were combined, which only works for a single table
+    DifferentTables {
+        left: String,
+        right: String,
+    },
+    /// The query is grouped by the `ordinal`-th selected column, but only selects `columns` columns
+    OrdinalOutOfRange {
+        ordinal: usize,
+        columns: usize,
+    },
 }
 
-impl<'a> Display for InsertQuery<'a> {
+impl Display for QueryError {
     fn fmt(&self, f: &mut Formatter) -> FormatResult {
-        write!(f, "{}", self.as_string())
+        match *self {
+            QueryError::Unsupported { feature, dialect } => {
+                write!(f, "{} are not supported by {}", feature, dialect)
+            },
+            QueryError::PageZero => write!(f, "page numbers start at 1"),
+            QueryError::PageOutOfRange { page, per_page } => {
+                write!(f, "the offset of page {} with {} rows per page is out of range", page, per_page)
+            },
+            QueryError::EmptyList => write!(f, "IN lists need at least one value"),
+            QueryError::DuplicateColumn { ref column } => {
+                write!(f, "the column {} is given more than once, ignoring case", column)
+            },
+            QueryError::TooLong { ref column, max_length } => {
+                write!(f, "the value of column {} is longer than {} characters", column, max_length)
+            },
+            QueryError::EmptyUnion => write!(f, "a UNION needs at least one query"),
+            QueryError::EmptyColumns => write!(f, "an aggregate of distinct values needs at least one column"),
+            QueryError::MissingOrderBy => write!(f, "a limit or offset requires an ORDER BY clause"),
+            QueryError::OrderNotSelected { ref column } => {
+                write!(f, "the distinct rows are ordered by {}, which is not selected", column)
+            },
+            QueryError::TooManyConditions { count, max } => {
+                write!(f, "the query has {} conditions, but at most {} are allowed", count, max)
+            },
+            QueryError::UnexpectedColumn { ref column } => write!(f, "the column {} is not expected here", column),
+            QueryError::ConflictingAssignment { ref column } => write!(f, "the column {} is assigned two different values", column),
+            QueryError::DifferentTables { ref left, ref right } => {
+                write!(f, "the queries update the different tables {} and {}", left, right)
+            },
+            QueryError::OrdinalOutOfRange { ordinal, columns } => {
+                write!(f, "the query groups by column {}, but only selects {} columns", ordinal, columns)
+            },
+        }
     }
 }
 
-#[allow(unused_assignments)]
-impl<'a> InsertQuery<'a> {
-    /// Creates a new [`InsertQuery`] that puts data into `table`.
-    /// 
-    /// [`InsertQuery`]: ./struct.InsertQuery.html
-    pub fn into(table: &'a str) -> InsertQuery<'a> {
-        InsertQuery {
-            into: table,
-            values: BTreeMap::new(),
+impl Error for QueryError {}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the things about a query that are valid SQL but likely mistakes
+///
+/// They are reported by e.g. [`SelectQuery::render_with_warnings`].
+///
+/// [`SelectQuery::render_with_warnings`]: ./struct.SelectQuery.html#method.render_with_warnings
+pub enum Warning {
+    /// An UPDATE or DELETE without any condition affects every row of the table
+    NoWhere,
+    /// A LIMIT without ORDER BY picks arbitrary rows, which can differ between runs
+    UnorderedLimit,
+    /// `SELECT *` returns whatever columns the table has when the query runs
+    SelectStar,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            Warning::NoWhere        => write!(f, "the query has no WHERE-clause and affects every row"),
+            Warning::UnorderedLimit => write!(f, "LIMIT without ORDER BY is nondeterministic"),
+            Warning::SelectStar     => write!(f, "SELECT * depends on the current columns of the table"),
         }
     }
+}
 
-    /// Returns a [`String`] that represents the [`InsertQuery`] in a valid SQL statement
+/// Trait implemented by everything that renders to a single SQL statement
+///
+/// This allows statements of different types to be handled together, e.g. in a
+/// [`QueryBatch`].
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{ToSql, SelectQuery, TransactionStmt};
+///
+/// let statements: Vec<Box<dyn ToSql>> = vec![
+///     Box::new(TransactionStmt::Begin),
+///     Box::new(SelectQuery::select(&["*"]).from("users")),
+/// ];
+///
+/// assert_eq!(statements[1].to_sql(), "SELECT * FROM users");
+/// ```
+///
+/// [`QueryBatch`]: ./struct.QueryBatch.html
+pub trait ToSql {
+    /// Returns the SQL of the statement, without a terminating semicolon
+    fn to_sql(&self) -> String;
+
+    /// Returns whether the statement only reads data, e.g. to send it to a replica
+    ///
+    /// Statements are assumed to write unless they say otherwise.
+    ///
     /// ## Example
-    /// ```
-    /// use query_builder::{Value, InsertQuery};
     ///
-    /// let mut q = InsertQuery::into("users");
-    /// q.values.insert("name", Value::Varchar("greg"));
+    /// ```
+    /// use query_builder::{ToSql, SelectQuery, DeleteQuery};
     ///
-    /// assert_eq!(q.as_string(), "INSERT INTO users(name) VALUES('greg')")
+    /// assert!(SelectQuery::select(&["*"]).from("users").is_read_only());
+    /// assert!(!DeleteQuery::from("users").is_read_only());
     /// ```
-    /// 
-    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
-    /// [`InsertQuery`]: ./struct.InsertQuery.html
-    pub fn as_string(&self) -> String {
-        let mut res = String::new();
-        let (mut vals, mut vals_list) = (String::new(), String::new());
-
-        res = format!("INSERT INTO {}", self.into);
-
-        if !self.values.is_empty() {
-            let mut keys = self.values.keys();
-            let key = keys.next().unwrap();
-            vals = format!("{}", key);
-            vals_list = format!("{}", self.values[key]);
+    fn is_read_only(&self) -> bool {
+        false
+    }
+}
 
-            for k in keys {
-                vals = format!("{}, {}", vals, k);
-                vals_list = format!("{}, {}", vals_list, self.values[k]);
-            }
-        }
+/// Trait for types describing a filter on the rows of a table, e.g. the parameters of a search
+///
+/// Filters are usually structs of `Option` fields, with a [`WhereClause`] requiring the column
+/// to equal the value for every field that is set. The companion `query_builder_derive` crate
+/// generates this for `#[derive(QueryFilter)]`, the trait can also be implemented by hand.
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{QueryFilter, SelectQuery, WhereClause, Value};
+///
+/// struct UserFilter<'a> {
+///     name: Option<&'a str>,
+///     age: Option<i32>,
+/// }
+///
+/// impl<'a> QueryFilter<'a> for UserFilter<'a> {
+///     fn to_where(&self) -> Vec<WhereClause<'a>> {
+///         let mut clauses = Vec::new();
+///         if let Some(name) = self.name {
+///             clauses.push(WhereClause::new("name", Value::Varchar(name)));
+///         }
+///         if let Some(age) = self.age {
+///             clauses.push(WhereClause::new("age", Value::Int(age)));
+///         }
+///         clauses
+///     }
+/// }
+///
+/// let mut query = SelectQuery::select(&["*"]).from("users");
+/// query.filter(&UserFilter { name: Some("anna"), age: None });
+///
+/// assert_eq!(query.as_string(), "SELECT * FROM users WHERE name = 'anna'");
+/// ```
+///
+/// [`WhereClause`]: ./struct.WhereClause.html
+pub trait QueryFilter<'a> {
+    /// Returns the clauses a row has to match, an empty list matches every row
+    fn to_where(&self) -> Vec<WhereClause<'a>>;
+}
 
-        format!("{}({}) VALUES({})", res, vals, vals_list)
-    }
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+/// Representing the way to Format the ORDER BY clause of some queries
+pub enum OrderBy<'a> {
+    Row(&'a str),
+    /// Like [`OrderBy::Row`] but with the explicit `ASC` keyword
+    ///
+    /// [`OrderBy::Row`]: ./enum.OrderBy.html#variant.Row
+    RowAsc(&'a str),
+    /// Sort the row in descending order
+    RowDesc(&'a str),
+    Expression(&'a str),
+    /// The wrapped ordering, with all NULLs sorted before or after the other values
+    ///
+    /// MySQL lacks `NULLS FIRST` and `NULLS LAST`, so there an extra sort key like
+    /// `(row IS NULL)` is used instead. SQL Server supports neither form.
+    Nulls(Box<OrderBy<'a>>, Nulls),
+    /// Sorts by the row in the given direction with all NULLs last, using an extra
+    /// `row IS NULL` sort key in every dialect, see [`OrderBy::nulls_last_expr`]
+    ///
+    /// [`OrderBy::nulls_last_expr`]: ./enum.OrderBy.html#method.nulls_last_expr
+    NullsLastExpr(&'a str, Direction),
+    /// The wrapped ordering, with the values compared using the named collation
+    ///
+    /// The name is quoted as an identifier of the dialect, see [`OrderBy::collate`].
+    ///
+    /// [`OrderBy::collate`]: ./enum.OrderBy.html#method.collate
+    Collate(Box<OrderBy<'a>>, &'a str),
 }
 
-#[derive(Debug)]
-/// Struct representing a SQL Delete Statement
-pub struct DeleteQuery<'a, 'c> {
-    from: &'a str,
-    pub whre: Vec<WhereClause<'a, 'c>>,
-    limit: Option<usize>,
-    order_by: Option<OrderBy<'c>>,
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the direction rows are sorted in
+pub enum Direction {
+    Asc,
+    Desc,
 }
 
-impl<'a, 'c> Display for DeleteQuery<'a, 'c> {
+impl Display for Direction {
     fn fmt(&self, f: &mut Formatter) -> FormatResult {
-        write!(f, "{}", self.as_string())
+        match *self {
+            Direction::Asc  => write!(f, "ASC"),
+            Direction::Desc => write!(f, "DESC"),
+        }
     }
 }
 
-#[allow(unused_assignments)]
-impl<'a, 'c> DeleteQuery<'a, 'c> {
-    /// Return a new [`DeleteQuery`] that deletes data from table `table`
-    /// 
-    /// [`DeleteQuery`]: ./struct.DeleteQuery.html
-    pub fn from(table: &'a str) -> DeleteQuery {
-        DeleteQuery {
-            from: table,
-            whre: Vec::new(),
-            limit: None,
-            order_by: None,
-        }
-    }
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing where NULLs are sorted by an [`OrderBy::Nulls`]
+///
+/// [`OrderBy::Nulls`]: ./enum.OrderBy.html#variant.Nulls
+pub enum Nulls {
+    First,
+    Last,
+}
 
-    /// Sets the limit of items to delete
+impl<'a> OrderBy<'a> {
+    /// Returns the [`String`] representation of the ORDER BY clause
+    ///
+    /// Ascending order is implicit for [`OrderBy::Row`], use [`OrderBy::RowAsc`]
+    /// if the `ASC` keyword should be written out.
+    ///
     /// ## Example
-    /// 
+    ///
     /// ```
-    /// use query_builder::{DeleteQuery, Value, WhereClause};
-    /// 
-    /// let mut query = DeleteQuery::from("users");
-    /// // add values to delete
-    /// query.whre.push(WhereClause::new("name", Value::Varchar("gregory"), None));
-    /// 
-    /// // add the limit
-    /// query.limit(1);
-    /// 
-    /// // make sure the query looks like expected
-    /// assert_eq!(query.as_string(), "DELETE FROM users WHERE name = 'gregory' LIMIT 1");
+    /// use query_builder::OrderBy;
+    ///
+    /// assert_eq!(OrderBy::Row("name").as_string(), "ORDER BY name");
+    /// assert_eq!(OrderBy::RowAsc("name").as_string(), "ORDER BY name ASC");
+    /// assert_eq!(OrderBy::RowDesc("name").as_string(), "ORDER BY name DESC");
     /// ```
-    pub fn limit(&mut self, limit: usize) {
-        self.limit = Some(limit);
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`OrderBy::Row`]: ./enum.OrderBy.html#variant.Row
+    /// [`OrderBy::RowAsc`]: ./enum.OrderBy.html#variant.RowAsc
+    pub fn as_string(&self) -> String {
+        self.render(&mut Context::literal(Dialect::Generic))
     }
 
-    /// Returns the limit of the [`DeleteQuery`]
+    /// Returns the ordering with NULLs sorted first
+    ///
     /// ## Example
-    /// 
+    ///
     /// ```
-    /// use query_builder::{DeleteQuery, Value};
-    /// 
-    /// // create query
-    /// let mut query = DeleteQuery::from("users");
-    /// 
-    /// // set the limit
-    /// query.limit(12);
-    /// 
-    /// assert_eq!(query.get_limit(), Some(12));
+    /// use query_builder::OrderBy;
+    ///
+    /// assert_eq!(OrderBy::RowDesc("born").nulls_first().as_string(), "ORDER BY born DESC NULLS FIRST");
     /// ```
-    /// 
-    /// [`DeleteQuery`]: ./struct.DeleteQuery.html
-    pub fn get_limit(&self) -> Option<usize> {
-        self.limit
+    pub fn nulls_first(self) -> OrderBy<'a> {
+        OrderBy::Nulls(Box::new(self), Nulls::First)
     }
 
-    /// Removes the limit from the [`DeleteQuery`]
-    /// 
+    /// Returns the ordering with NULLs sorted last
+    ///
     /// ## Example
-    /// 
+    ///
     /// ```
-    /// use query_builder::DeleteQuery;
-    /// 
-    /// let mut query = DeleteQuery::from("users");
-    /// /* add limit to the query */
-    /// query.limit(12);
-    /// 
-    /// assert_eq!(query.get_limit(), Some(12));
-    /// 
-    /// /* clear the limit */
-    /// query.clear_limit();
-    /// 
-    /// assert_eq!(query.get_limit(), None);
-    /// 
+    /// use query_builder::OrderBy;
+    ///
+    /// assert_eq!(OrderBy::Row("born").nulls_last().as_string(), "ORDER BY born NULLS LAST");
     /// ```
-    /// [`DeleteQuery`]: ./struct.DeleteQuery.html
-    pub fn clear_limit(&mut self) {
-        self.limit = None;
+    pub fn nulls_last(self) -> OrderBy<'a> {
+        OrderBy::Nulls(Box::new(self), Nulls::Last)
     }
 
-    /// Adds a [`OrderBy`] clause to the query
-    /// 
+    /// Returns an ordering by `column` in direction `dir` with all NULLs sorted last
+    ///
+    /// Unlike [`nulls_last`] this does not depend on the dialect, the NULLs are always
+    /// sorted by an extra `column IS NULL` key, as FALSE sorts before TRUE.
+    ///
     /// ## Example
-    /// 
+    ///
     /// ```
-    /// use query_builder::{DeleteQuery, OrderBy};
-    /// 
-    /// let mut query = DeleteQuery::from("continents");
-    /// query.order_by(OrderBy::Row("population"));
-    /// 
-    /// assert_eq!(query.as_string(), "DELETE FROM continents ORDER BY population");
+    /// use query_builder::{OrderBy, Direction};
+    ///
+    /// assert_eq!(
+    ///     OrderBy::nulls_last_expr("born", Direction::Desc).as_string(),
+    ///     "ORDER BY born IS NULL, born DESC"
+    /// );
     /// ```
-    /// [`OrderBy`]: ./enum.OrderBy.html
-    pub fn order_by(&mut self, ob: OrderBy<'c>) {
-        self.order_by = Some(ob);
+    ///
+    /// [`nulls_last`]: #method.nulls_last
+    pub fn nulls_last_expr(column: &'a str, dir: Direction) -> OrderBy<'a> {
+        OrderBy::NullsLastExpr(column, dir)
     }
 
-    /// Returns either `true` or `false` depending on whether
-    /// or not the [`DeleteQuery`] contains a [`OrderBy`] clause
-    /// 
+    /// Returns the ordering with the values compared using the collation `name`, e.g. for locale-aware sorting
+    ///
+    /// The name is written in double quotes, in backticks for MySQL and as it is for
+    /// SQL Server, which does not quote collation names.
+    ///
     /// ## Example
-    /// 
+    ///
     /// ```
-    /// use query_builder::{DeleteQuery, OrderBy};
-    /// 
-    /// let mut query = DeleteQuery::from("states");
-    /// query.order_by(OrderBy::Row("population"));
-    /// 
-    /// assert!(query.is_ordered());
+    /// use query_builder::OrderBy;
+    ///
+    /// assert_eq!(OrderBy::RowDesc("name").collate("en_US").as_string(), "ORDER BY name COLLATE \"en_US\" DESC");
     /// ```
-    /// [`DeleteQuery`]: ./struct.DeleteQuery.html
-    /// [`OrderBy`]: ./enum.OrderBy.html
-    pub fn is_ordered(&self) -> bool {
-        if let Some(_) = self.order_by {
-            true
-        }
-        else {
-            false
-        }
+    pub fn collate(self, name: &'a str) -> OrderBy<'a> {
+        OrderBy::Collate(Box::new(self), name)
     }
 
-    /// Removes the ORDER BY clause from the query
-    pub fn clear_order_by(&mut self) {
-        self.order_by = None;
+    /// Returns the column or expression that is sorted by, as it was given
+    fn column(&self) -> &'a str {
+        match *self {
+            OrderBy::Row(r) | OrderBy::RowAsc(r) | OrderBy::RowDesc(r) => r,
+            OrderBy::Expression(e) => e,
+            OrderBy::NullsLastExpr(r, _) => r,
+            OrderBy::Nulls(ref o, _) | OrderBy::Collate(ref o, _) => o.column(),
+        }
     }
 
-    /// Return a [`String`] representing the [`DeleteQuery`]
-    /// 
-    /// ## Example
-    /// ```
-    /// use query_builder::{DeleteQuery, Value, WhereClause, Condition};
-    /// 
-    /// // create basic query
-    /// let mut query = DeleteQuery::from("people");
-    /// 
-    /// // set parameter of the query
-    /// query.whre.push(WhereClause::new("name", Value::Varchar("justine"), None));
-    /// query.whre.push(WhereClause::new("age", Value::Int(24), Some(Condition::And)));
-    /// query.limit(1);
-    /// 
-    /// assert_eq!(query.as_string(), "DELETE FROM people WHERE name = 'justine' AND age = 24 LIMIT 1");
-    /// ```
-    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
-    pub fn as_string(&self) -> String {
-        let mut res = String::new();
-
-        res = format!("DELETE FROM {}", self.from);
-
-        if !self.whre.is_empty() {
-            /* get the first element from the vector */
-            let c = &self.whre[0];
-            res = format!("{} {}", res, c.as_string_no_cond_with_prefix());
-            for clause in &self.whre[1..] {
-                res = format!("{} {}", res, clause);
-            }
+    /// Returns the sort key and the direction of the ordering
+    fn key(&self, ctx: &Context) -> (String, String) {
+        match *self {
+            OrderBy::Row(r) => (ctx.ident(r), String::new()),
+            OrderBy::RowAsc(r) => (ctx.ident(r), ctx.kw(" ASC")),
+            OrderBy::RowDesc(r) => (ctx.ident(r), ctx.kw(" DESC")),
+            OrderBy::Expression(e) => (e.to_string(), String::new()),
+            OrderBy::Nulls(ref o, _) => o.key(ctx),
+            OrderBy::Collate(ref o, name) => {
+                let (key, direction) = o.key(ctx);
+                let name = match ctx.dialect {
+                    Dialect::MySql => format!("`{}`", name.replace('`', "``")),
+                    Dialect::SqlServer => name.to_string(),
+                    _ => format!("\"{}\"", name.replace('"', "\"\"")),
+                };
+                (ctx.case().format("{} COLLATE {}", &[&key, &name]), direction)
+            },
+            OrderBy::NullsLastExpr(r, Direction::Asc) => (ctx.ident(r), ctx.kw(" ASC")),
+            OrderBy::NullsLastExpr(r, Direction::Desc) => (ctx.ident(r), ctx.kw(" DESC")),
         }
+    }
 
-        if let Some(ref o) = self.order_by {
-            res = format!("{} {}", res, o);
-        }
+    /// Renders the ORDER BY clause for the dialect of `ctx`
+    fn render(&self, ctx: &mut Context) -> String {
+        let (key, direction) = self.key(ctx);
+        let case = ctx.case();
 
-        if let Some(l) = self.limit {
-            res = format!("{} LIMIT {}", res, l);
+        match *self {
+            OrderBy::Nulls(_, nulls) => {
+                ctx.require("NULLS FIRST and NULLS LAST", &[Dialect::Generic, Dialect::MySql, Dialect::Postgres, Dialect::Sqlite]);
+                match (ctx.dialect, nulls) {
+                    /* FALSE sorts before TRUE */
+                    (Dialect::MySql, Nulls::First) => case.format("ORDER BY ({} IS NOT NULL), {}{}", &[&key, &key, &direction]),
+                    (Dialect::MySql, Nulls::Last) => case.format("ORDER BY ({} IS NULL), {}{}", &[&key, &key, &direction]),
+                    (_, Nulls::First) => case.format("ORDER BY {}{} NULLS FIRST", &[&key, &direction]),
+                    (_, Nulls::Last) => case.format("ORDER BY {}{} NULLS LAST", &[&key, &direction]),
+                }
+            },
+            OrderBy::NullsLastExpr(..) => case.format("ORDER BY {} IS NULL, {}{}", &[&key, &key, &direction]),
+            _ => case.format("ORDER BY {}{}", &[&key, &direction]),
         }
-
-        res
     }
 }
 
-#[derive(Debug)]
-/// Struct representing an SQL Update statement
-pub struct UpdateQuery<'a, 'c> {
-    update: &'a str,
-    /// A Map containing the field to set with the appropiate values to them
-    pub set: BTreeMap<&'a str, Value<'c>>,
-    /// All [`WhereClause`]s for conditional Updating in this 
-    /// [`UpdateQuery`]
-    /// 
-    /// [`WhereClause`]: ./struct.WhereClause.html
-    /// [`UpdateQuery`]: ./struct.UpdateQuery.html
-    pub whre: Vec<WhereClause<'a, 'c>>,
-    limit: Option<usize>,
-}
-
-impl<'a, 'c> Display for UpdateQuery<'a, 'c> {
+impl<'a> Display for OrderBy<'a> {
     fn fmt(&self, f: &mut Formatter) -> FormatResult {
         write!(f, "{}", self.as_string())
     }
 }
 
-#[allow(unused_assignments)]
-impl<'a, 'c> UpdateQuery<'a, 'c> {
-    /// Returns a new [`UpdateQuery`] that updates the table `table`
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+/// Struct representing an WHERE-Clause
+/// 
+/// 
+pub struct WhereClause<'a> {
+    tbl: &'a str,
+    op: Operator,
+    quantifier: Option<Quantifier>,
+    cond: Value<'a>,
+    comment: Option<&'a str>,
+    reversed: bool,
+    bare: bool,
+    escape: Option<char>,
+}
+
+impl<'a> WhereClause<'a> {
+    /// Creates a new WHERE-clause
     /// 
-    /// ## Example
+    /// The clause itself does not know how it is combined with other clauses, the
+    /// [`Condition`] is given when it is added to a [`WhereList`]. This allows the same
+    /// clause to be used with `AND` in one query and with `OR` in another.
     /// 
+    /// [`Condition`]: ./enum.Condition.html
+    /// [`WhereList`]: ./struct.WhereList.html
+    ///  
+    pub fn new(table: &'a str, cond: Value<'a>) -> WhereClause<'a> {
+        WhereClause::with_operator(table, Operator::Eq, cond)
+    }
+
+    /// Creates a new WHERE-clause comparing `table` with `cond` using `op`
+    ///
+    /// Works just like [`WhereClause::new`], which always uses [`Operator::Eq`].
+    ///
+    /// ## Example
+    ///
     /// ```
-    /// use query_builder::UpdateQuery;
-    /// 
-    /// let query = UpdateQuery::update("town");
-    /// 
-    /// assert_eq!(query.as_string(), "UPDATE town");
+    /// use query_builder::{WhereClause, Operator, Value};
+    ///
+    /// let clause = WhereClause::with_operator("age", Operator::GtEq, Value::Int(18));
+    /// assert_eq!(clause.as_string_no_cond(), "age >= 18");
+    ///
+    /// let list = Value::List(vec![Value::Int(1), Value::Int(2)]);
+    /// let clause = WhereClause::with_operator("id", Operator::In, list);
+    /// assert_eq!(clause.as_string_no_cond(), "id IN (1, 2)");
     /// ```
-    /// 
-    /// [`UpdateQuery`]: ./struct.UpdateQuery.html
-    pub fn update(table: &'a str) -> UpdateQuery {
-        UpdateQuery {
-            update: table,
-            set: BTreeMap::new(),
-            whre: Vec::new(),
-            limit: None,
+    ///
+    /// [`WhereClause::new`]: #method.new
+    /// [`Operator::Eq`]: ./enum.Operator.html#variant.Eq
+    pub fn with_operator(table: &'a str, op: Operator, cond: Value<'a>) -> WhereClause<'a> {
+        WhereClause {
+            tbl: table,
+            op,
+            quantifier: None,
+            cond,
+            comment: None,
+            reversed: false,
+            bare: false,
+            escape: None,
         }
     }
 
-    /// Set the limit of the Query to the value of `l`
+    /// Creates a new WHERE-clause that holds if `table` compared with any of the values of `cond` using `op` is true
+    ///
+    /// `cond` should be a [`Value::Subquery`] or a [`Value::Array`].
+    ///
     /// ## Example
-    /// 
+    ///
     /// ```
-    /// use query_builder::{UpdateQuery, Value};
-    /// 
-    /// let mut query = UpdateQuery::update("users");
-    /// // set the limit
-    /// query.limit(12);
-    /// 
-    /// // assert that the limit is actually there
-    /// assert_eq!(query.get_limit(), Some(12))
+    /// use query_builder::{WhereClause, Operator, Value};
+    ///
+    /// let ids = Value::Array(vec![Value::Int(1), Value::Int(2)]);
+    /// let clause = WhereClause::any("id", Operator::Eq, ids);
+    /// assert_eq!(clause.as_string_no_cond(), "id = ANY (ARRAY[1, 2])");
     /// ```
-    pub fn limit(&mut self, l: usize) {
-        self.limit = Some(l);
+    ///
+    /// [`Value::Subquery`]: ./enum.Value.html#variant.Subquery
+    /// [`Value::Array`]: ./enum.Value.html#variant.Array
+    pub fn any(table: &'a str, op: Operator, cond: Value<'a>) -> WhereClause<'a> {
+        WhereClause {
+            quantifier: Some(Quantifier::Any),
+            ..WhereClause::with_operator(table, op, cond)
+        }
     }
 
-    /// Returns whether or not the [`UpdateQuery`] has a limit
-    /// 
+    /// Creates a new WHERE-clause that holds if `table` compared with all of the values of `cond` using `op` is true
+    ///
+    /// `cond` should be a [`Value::Subquery`] or a [`Value::Array`].
+    ///
     /// ## Example
-    /// 
+    ///
     /// ```
-    /// use query_builder::UpdateQuery;
-    ///  
-    /// // create the query
-    /// let mut query = UpdateQuery::update("cities");
-    /// 
-    /// assert_eq!(query.has_limit(), false);
-    /// 
-    /// // set limit
-    /// query.limit(1);
-    /// assert_eq!(query.has_limit(), true);
+    /// use query_builder::{WhereClause, Operator, Value, SelectQuery};
+    ///
+    /// let prices = SelectQuery::select(&["price"]).from("offers");
+    /// let clause = WhereClause::all("price", Operator::Gt, Value::Subquery(Box::new(prices)));
+    /// assert_eq!(clause.as_string_no_cond(), "price > ALL (SELECT price FROM offers)");
     /// ```
-    /// [`UpdateQuery`]: ./struct.UpdateQuery.html
-    pub fn has_limit(&self) -> bool {
-        if let Some(_) = self.limit {
-            return true;
+    ///
+    /// [`Value::Subquery`]: ./enum.Value.html#variant.Subquery
+    /// [`Value::Array`]: ./enum.Value.html#variant.Array
+    pub fn all(table: &'a str, op: Operator, cond: Value<'a>) -> WhereClause<'a> {
+        WhereClause {
+            quantifier: Some(Quantifier::All),
+            ..WhereClause::with_operator(table, op, cond)
         }
-
-        false
     }
-    /// Returns the limit of the [`UpdateQuery`] if there is one
-    /// 
+
+    /// Creates a new WHERE-clause that holds if the boolean `column` is true
+    ///
+    /// The column is rendered on its own instead of being compared with `TRUE`.
+    ///
     /// ## Example
-    /// 
+    ///
     /// ```
-    /// use query_builder::UpdateQuery;
-    /// // create the query
-    /// let mut query = UpdateQuery::update("countries");
-    /// 
-    /// assert_eq!(query.get_limit(), None);
-    /// 
-    /// // set the limit
-    /// query.limit(12);
-    /// assert_eq!(query.get_limit(), Some(12));
+    /// use query_builder::WhereClause;
+    ///
+    /// let clause = WhereClause::is_true("active");
+    /// assert_eq!(clause.as_string_no_cond(), "active");
+    /// assert_eq!(clause.negate().as_string_no_cond(), "NOT active");
     /// ```
-    /// [`UpdateQuery`]: ./struct.UpdateQuery.html
-    pub fn get_limit(&self) -> Option<usize> {
-        self.limit
+    pub fn is_true(column: &'a str) -> WhereClause<'a> {
+        WhereClause {
+            bare: true,
+            ..WhereClause::new(column, Value::Bool(true))
+        }
     }
 
-    /// Returns the [`String`] representation of the [`UpdateQuery`]
-    /// 
+    /// Creates a new WHERE-clause that holds if the boolean `column` is false, rendered as `NOT column`
+    ///
     /// ## Example
-    /// 
+    ///
     /// ```
-    /// use query_builder::{UpdateQuery, Value};
-    /// 
-    /// let mut query = UpdateQuery::update("users");
-    /// 
-    /// query.set.insert("name", Value::Varchar("jeff")); 
-    /// query.limit(1);
-    /// 
-    /// assert_eq!(query.as_string(),"UPDATE users SET name = 'jeff' LIMIT 1");
+    /// use query_builder::WhereClause;
+    ///
+    /// let clause = WhereClause::is_false("deleted");
+    /// assert_eq!(clause.as_string_no_cond(), "NOT deleted");
     /// ```
-    /// [`UpdateQuery`]: ./struct.UpateQuery.html
-    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
-    pub fn as_string(&self) -> String {
-        let mut res = String::new();
-
-        res = format!("UPDATE {}", self.update);
-
-        if !self.set.is_empty() {
-            let mut keys = self.set.keys();
-            let key = keys.next().unwrap();
-
-            res = format!("{} SET {} = {}", res, key, self.set[key]);
-
-            for k in keys {
-                res = format!("{}, {} = {}", res, k, self.set[k]);
-            }
+    pub fn is_false(column: &'a str) -> WhereClause<'a> {
+        WhereClause {
+            bare: true,
+            ..WhereClause::new(column, Value::Bool(false))
         }
+    }
 
-        if !self.whre.is_empty() {
-            let c = &self.whre[0];
-            res = format!("{} {}", res, c.as_string_no_cond_with_prefix());
-            for clause in &self.whre[1..] {
-                res = format!("{} {}", res, clause);
-            }
-        }
+    /// Creates a `LIKE` clause that holds if `column` contains the text `s`
+    ///
+    /// The pattern is built with [`Value::like_contains`] and the clause always carries
+    /// `ESCAPE '\'`, so wildcards in `s` are matched literally on every database.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::WhereClause;
+    ///
+    /// let clause = WhereClause::contains("title", "50%");
+    /// assert_eq!(clause.as_string_no_cond(), "title LIKE '%50\\%%' ESCAPE '\\'");
+    /// ```
+    ///
+    /// [`Value::like_contains`]: ./enum.Value.html#method.like_contains
+    pub fn contains(column: &'a str, s: &str) -> WhereClause<'a> {
+        WhereClause::with_operator(column, Operator::Like, Value::like_contains(s)).escape('\\')
+    }
 
-        if let Some(l) = self.limit {
-            res = format!("{} LIMIT {}", res, l);
-        }
+    /// Creates a `LIKE` clause that holds if `column` starts with the text `s`, see [`contains`]
+    ///
+    /// [`contains`]: #method.contains
+    pub fn starts_with(column: &'a str, s: &str) -> WhereClause<'a> {
+        WhereClause::with_operator(column, Operator::Like, Value::like_starts_with(s)).escape('\\')
+    }
 
-        res
+    /// Creates a `LIKE` clause that holds if `column` ends with the text `s`, see [`contains`]
+    ///
+    /// [`contains`]: #method.contains
+    pub fn ends_with(column: &'a str, s: &str) -> WhereClause<'a> {
+        WhereClause::with_operator(column, Operator::Like, Value::like_ends_with(s)).escape('\\')
     }
+
+    /// Renders the value on the left side of the comparison and the column on the right
+    ///
+    /// Ordering comparisons are mirrored so the clause still matches the same rows.
+    /// `LIKE`, `IN`, `IS` and quantified comparisons can not be turned around and
+    /// always keep the column on the left.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{WhereClause, Operator, Value};
+    ///
+    /// let clause = WhereClause::new("status", Value::Varchar("active")).reversed();
+    /// assert_eq!(clause.as_string_no_cond(), "'active' = status");
+    ///
+    /// let clause = WhereClause::with_operator("age", Operator::Gt, Value::Int(18)).reversed();
+    /// assert_eq!(clause.as_string_no_cond(), "18 < age");
+    /// ```
+    pub fn reversed(mut self) -> Self {
+        self.reversed = true;
+        self
+    }
+
+    /// Attaches the note `comment` to the clause, which is rendered as an SQL comment after it
+    ///
+    /// This helps to find out which part of the code added a condition to a query.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{WhereClause, Value};
+    ///
+    /// let clause = WhereClause::new("active", Value::Bool(true)).comment("added by the admin filter");
+    /// assert_eq!(clause.as_string_no_cond(), "active = TRUE /* added by the admin filter */");
+    /// ```
+    pub fn comment(mut self, comment: &'a str) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    /// Sets the character that makes a `%` or `_` in the pattern of a `LIKE` match literally
+    ///
+    /// The `ESCAPE` clause is left out for all other operators.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{WhereClause, Operator, Value};
+    ///
+    /// let clause = WhereClause::with_operator("discount", Operator::Like, Value::Varchar("50!%")).escape('!');
+    /// assert_eq!(clause.as_string_no_cond(), "discount LIKE '50!%' ESCAPE '!'");
+    /// ```
+    pub fn escape(mut self, escape: char) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Removes repeated values from the list of an `IN` or `NOT IN` clause
+    ///
+    /// Every value is kept where it first appears. Values of other clauses are left as they are.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{WhereClause, Operator, Value};
+    ///
+    /// let ids = Value::List(vec![Value::Int(3), Value::Int(1), Value::Int(3)]);
+    /// let clause = WhereClause::with_operator("id", Operator::In, ids).dedup();
+    /// assert_eq!(clause.as_string_no_cond(), "id IN (3, 1)");
+    /// ```
+    pub fn dedup(mut self) -> Self {
+        if self.op == Operator::In || self.op == Operator::NotIn {
+            if let Value::List(ref mut l) = self.cond {
+                let mut seen = BTreeSet::new();
+                l.retain(|v| seen.insert(v.clone()));
+            }
+        }
+        self
+    }
+
+    /// Returns the logical negation of the [`WhereClause`]
+    ///
+    /// The operator is replaced by its [`Operator::negate`] counterpart and a quantifier
+    /// is swapped, as `NOT (a > ALL s)` equals `a <= ANY s`. The way the clause is combined
+    /// with the others stays the same.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{WhereClause, Operator, Value};
+    ///
+    /// let clause = WhereClause::with_operator("name", Operator::Like, Value::Varchar("a%"));
+    /// assert_eq!(clause.negate().as_string_no_cond(), "name NOT LIKE 'a%'");
+    /// ```
+    ///
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    /// [`Operator::negate`]: ./enum.Operator.html#method.negate
+    pub fn negate(&self) -> WhereClause<'a> {
+        WhereClause {
+            op: self.op.negate(),
+            quantifier: self.quantifier.map(|q| match q {
+                Quantifier::Any => Quantifier::All,
+                Quantifier::All => Quantifier::Any,
+            }),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a [`String`] representing the [`WhereClause`]
+    /// The returned [`String`] is also the default representation returned when calling the 
+    /// [`Display`] trait functions on this struct.
+    ///
+    /// Since a clause does not carry a [`Condition`], this is the same as [`as_string_no_cond`].
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::{WhereClause, Value};
+    /// 
+    /// let wclause = WhereClause::new("user", Value::Varchar("gerald"));
+    /// 
+    /// assert_eq!(wclause.as_string(), "user = 'gerald'")
+    /// ```
+    /// 
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+    /// [`Condition`]: ./enum.Condition.html
+    /// [`as_string_no_cond`]: #method.as_string_no_cond
+    pub fn as_string(&self) -> String {
+        self.as_string_no_cond()
+    }
+
+    /// Returns a [`String`] representing the [`WhereClause`] without it's condition part
+    /// but with the `WHERE` phrase in the beginning
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::{WhereClause, Value};
+    /// 
+    /// let clause = WhereClause::new("user", Value::Varchar("thomas"));
+    /// 
+    /// assert_eq!(clause.as_string_no_cond_with_prefix(), "WHERE user = 'thomas'")
+    /// ```
+    /// 
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn as_string_no_cond_with_prefix(&self) -> String {
+        format!("WHERE {}", self.as_string_no_cond())
+    }
+
+    /// Returns a [`String`] representing the [`WhereClause`] without `WHERE` prefix and 
+    /// without it's conditional parts
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::{WhereClause, Value};
+    /// 
+    /// let clause = WhereClause::new("user", Value::Varchar("jeanny"));
+    /// 
+    /// assert_eq!(clause.as_string_no_cond(), "user = 'jeanny'")
+    /// ```
+    /// 
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    pub fn as_string_no_cond(&self) -> String {
+        self.render(&mut Context::literal(Dialect::Generic))
+    }
+
+    /// Renders the clause without its condition part, passing the value through `ctx`
+    fn render(&self, ctx: &mut Context<'a>) -> String {
+        let mut res = self.render_comparison(ctx);
+
+        match (self.op, self.escape) {
+            (Operator::Like, Some(e)) | (Operator::NotLike, Some(e)) => {
+                /* a quote has to be doubled inside the literal, MySQL also reads a backslash as an escape */
+                let e = match e {
+                    '\'' => "''".to_string(),
+                    '\\' if ctx.dialect == Dialect::MySql => "\\\\".to_string(),
+                    e => e.to_string(),
+                };
+                res.push_str(&ctx.case().format(" ESCAPE '{}'", &[&e]));
+            },
+            _ => {},
+        }
+
+        match self.comment {
+            /* a closing marker in the note would end the comment early */
+            Some(c) => format!("{} /* {} */", res, c.replace("*/", "* /")),
+            None => res,
+        }
+    }
+
+    /// Renders the comparison of the clause, without its comment
+    fn render_comparison(&self, ctx: &mut Context<'a>) -> String {
+        if self.bare {
+            /* a negated `is_true` compares with TRUE using `<>` */
+            return if (self.cond == Value::Bool(true)) == (self.op == Operator::Eq) {
+                ctx.ident(self.tbl)
+            } else {
+                ctx.case().format("NOT {}", &[&ctx.ident(self.tbl)])
+            };
+        }
+
+        if let Some(q) = self.quantifier {
+            let values = ctx.value(self.tbl, &self.cond);
+            return match self.cond {
+                /* a subquery already brings its own parentheses */
+                Value::Subquery(_) => format!("{} {} {} {}", ctx.ident(self.tbl), ctx.kw(self.op), ctx.kw(q), values),
+                _ => format!("{} {} {} ({})", ctx.ident(self.tbl), ctx.kw(self.op), ctx.kw(q), values),
+            };
+        }
+
+        let mirrored = match self.op {
+            Operator::Eq | Operator::NotEq => Some(self.op),
+            Operator::DistinctFrom | Operator::NotDistinctFrom => Some(self.op),
+            Operator::Lt    => Some(Operator::Gt),
+            Operator::LtEq  => Some(Operator::GtEq),
+            Operator::Gt    => Some(Operator::Lt),
+            Operator::GtEq  => Some(Operator::LtEq),
+            _ => None,
+        };
+
+        match self.op {
+            /* `IS ?` is not valid SQL */
+            Operator::Is | Operator::IsNot => {
+                let cond = match self.cond {
+                    Value::Null | Value::Bool(_) => ctx.kw(&self.cond),
+                    _ => self.cond.as_string(),
+                };
+                format!("{} {} {}", ctx.ident(self.tbl), ctx.kw(self.op), cond)
+            },
+            Operator::DistinctFrom | Operator::NotDistinctFrom if ctx.dialect == Dialect::MySql => {
+                let same = format!("{} <=> {}", ctx.ident(self.tbl), ctx.value(self.tbl, &self.cond));
+                match self.op {
+                    Operator::DistinctFrom => ctx.case().format("NOT ({})", &[&same]),
+                    _ => same,
+                }
+            },
+            _ => match mirrored {
+                Some(op) if self.reversed => format!("{} {} {}", ctx.value(self.tbl, &self.cond), ctx.kw(op), ctx.ident(self.tbl)),
+                _ => format!("{} {} {}", ctx.ident(self.tbl), ctx.kw(self.op), ctx.value(self.tbl, &self.cond)),
+            },
+        }
+    }
+}
+
+impl<'a> Display for WhereClause<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+/// Struct representing the list of [`WhereClause`]s of a query
+///
+/// Every entry pairs a clause with the [`Condition`] combining it with the entries
+/// before it, the condition of the first entry is never rendered. The clauses themselves
+/// do not carry a condition, so one clause can be added with `AND` to one list and with
+/// `OR` to another.
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{SelectQuery, WhereClause, Value};
+///
+/// let vip = WhereClause::new("vip", Value::Bool(true));
+///
+/// let mut q = SelectQuery::select(&["*"]).from("users");
+/// q.whre.push(WhereClause::new("active", Value::Bool(true)));
+/// q.whre.push(vip.clone());
+/// assert_eq!(q.as_string(), "SELECT * FROM users WHERE active = TRUE AND vip = TRUE");
+///
+/// let mut q = SelectQuery::select(&["*"]).from("users");
+/// q.whre.push(WhereClause::new("admin", Value::Bool(true)));
+/// q.whre.push_or(vip);
+/// assert_eq!(q.as_string(), "SELECT * FROM users WHERE admin = TRUE OR vip = TRUE");
+/// ```
+///
+/// [`WhereClause`]: ./struct.WhereClause.html
+/// [`Condition`]: ./enum.Condition.html
+pub struct WhereList<'a> {
+    entries: Vec<(Condition, WhereClause<'a>)>,
+}
+
+impl<'a> WhereList<'a> {
+    /// Creates an empty list
+    pub fn new() -> Self {
+        WhereList::default()
+    }
+
+    /// Adds `clause`, combined with the entries before it using `AND`
+    pub fn push(&mut self, clause: WhereClause<'a>) {
+        self.push_with(Condition::And, clause);
+    }
+
+    /// Adds `clause`, combined with the entries before it using `OR`
+    pub fn push_or(&mut self, clause: WhereClause<'a>) {
+        self.push_with(Condition::Or, clause);
+    }
+
+    /// Adds `clause`, combined with the entries before it using `how`
+    pub fn push_with(&mut self, how: Condition, clause: WhereClause<'a>) {
+        self.entries.push((how, clause));
+    }
+
+    /// Returns the number of clauses in the list
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no clauses in the list
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all clauses from the list
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the entries of the list, each clause along with its [`Condition`]
+    ///
+    /// [`Condition`]: ./enum.Condition.html
+    pub fn iter(&self) -> ::std::slice::Iter<'_, (Condition, WhereClause<'a>)> {
+        self.entries.iter()
+    }
+
+    /// Returns the clauses of the list without their conditions
+    fn clauses(&self) -> impl Iterator<Item = &WhereClause<'a>> {
+        self.entries.iter().map(|e| &e.1)
+    }
+}
+
+impl<'a> Index<usize> for WhereList<'a> {
+    type Output = WhereClause<'a>;
+
+    /// Returns the `i`-th clause of the list
+    ///
+    /// ## Panics
+    ///
+    /// Panics if there are not more than `i` clauses.
+    fn index(&self, i: usize) -> &WhereClause<'a> {
+        &self.entries[i].1
+    }
+}
+
+/* clauses without a condition are combined using AND */
+impl<'a> Extend<WhereClause<'a>> for WhereList<'a> {
+    fn extend<I: IntoIterator<Item = WhereClause<'a>>>(&mut self, clauses: I) {
+        for c in clauses {
+            self.push(c);
+        }
+    }
+}
+
+impl<'a> Extend<(Condition, WhereClause<'a>)> for WhereList<'a> {
+    fn extend<I: IntoIterator<Item = (Condition, WhereClause<'a>)>>(&mut self, entries: I) {
+        self.entries.extend(entries);
+    }
+}
+
+impl<'a> IntoIterator for WhereList<'a> {
+    type Item = (Condition, WhereClause<'a>);
+    type IntoIter = ::std::vec::IntoIter<(Condition, WhereClause<'a>)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Struct representing a tree of conditions, for WHERE-clauses that need parentheses
+///
+/// Unlike the flat list of [`WhereClause`]s in `whre`, a [`Cond`] can nest conditions. The
+/// parts are combined in the order they are added, a [`Cond`] of several parts that is added
+/// to another one is put in parentheses. Use [`group`] to put a condition in parentheses
+/// even if it has a single part.
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{Cond, SelectQuery, WhereClause, Value};
+///
+/// let cond = Cond::group(Cond::new(WhereClause::new("a", Value::Int(1)))
+///         .and(WhereClause::new("b", Value::Int(2))))
+///     .or(Cond::group(Cond::new(WhereClause::new("c", Value::Int(3)))));
+///
+/// let mut q = SelectQuery::select(&["*"]).from("t");
+/// q.where_cond(cond);
+///
+/// assert_eq!(q.as_string(), "SELECT * FROM t WHERE (a = 1 AND b = 2) OR (c = 3)");
+/// ```
+///
+/// [`WhereClause`]: ./struct.WhereClause.html
+/// [`Cond`]: ./struct.Cond.html
+/// [`group`]: #method.group
+pub struct Cond<'a> {
+    parts: Vec<(Condition, CondPart<'a>)>,
+    grouped: bool,
+}
+
+#[derive(Debug, Clone)]
+/// A single part of a [`Cond`]
+///
+/// [`Cond`]: ./struct.Cond.html
+enum CondPart<'a> {
+    Clause(WhereClause<'a>),
+    Nested(Cond<'a>),
+}
+
+impl<'a> From<WhereClause<'a>> for Cond<'a> {
+    fn from(clause: WhereClause<'a>) -> Cond<'a> {
+        Cond::new(clause)
+    }
+}
+
+impl<'a> Cond<'a> {
+    /// Creates a new condition consisting of `clause` only
+    pub fn new(clause: WhereClause<'a>) -> Cond<'a> {
+        Cond {
+            parts: vec![(Condition::And, CondPart::Clause(clause))],
+            grouped: false,
+        }
+    }
+
+    /// Puts `cond` in parentheses
+    pub fn group(cond: Cond<'a>) -> Cond<'a> {
+        Cond {
+            parts: vec![(Condition::And, CondPart::Nested(Cond { grouped: true, ..cond }))],
+            grouped: false,
+        }
+    }
+
+    /// Combines the condition with `other` using `AND`
+    pub fn and<C: Into<Cond<'a>>>(self, other: C) -> Self {
+        self.push(Condition::And, other.into())
+    }
+
+    /// Combines the condition with `other` using `OR`
+    pub fn or<C: Into<Cond<'a>>>(self, other: C) -> Self {
+        self.push(Condition::Or, other.into())
+    }
+
+    /// Adds `other` as the next part, unwrapping it if it is a single ungrouped clause
+    fn push(mut self, how: Condition, mut other: Cond<'a>) -> Self {
+        let part = if !other.grouped && other.parts.len() == 1 {
+            other.parts.remove(0).1
+        } else {
+            CondPart::Nested(other)
+        };
+        self.parts.push((how, part));
+        self
+    }
+
+    /// Returns whether the condition needs parentheses when combined with `AND`
+    fn has_or(&self) -> bool {
+        self.parts[1..].iter().any(|p| p.0 == Condition::Or)
+    }
+
+    /// Returns all clauses of the condition in the order they are rendered
+    fn clauses(&self) -> Vec<&WhereClause<'a>> {
+        let mut res = Vec::new();
+        for p in &self.parts {
+            match p.1 {
+                CondPart::Clause(ref c) => res.push(c),
+                CondPart::Nested(ref n) => res.extend(n.clauses()),
+            }
+        }
+        res
+    }
+
+    /// Renders the condition, passing the values through `ctx`
+    fn render(&self, ctx: &mut Context<'a>) -> String {
+        let mut res = String::new();
+        for (i, p) in self.parts.iter().enumerate() {
+            if i > 0 {
+                res.push_str(&format!(" {} ", ctx.kw(&p.0)));
+            }
+            match p.1 {
+                CondPart::Clause(ref c) => res.push_str(&c.render(ctx)),
+                /* a grouped condition brings its own parentheses */
+                CondPart::Nested(ref n) if !n.grouped => res.push_str(&format!("({})", n.render(ctx))),
+                CondPart::Nested(ref n) => res.push_str(&n.render(ctx)),
+            }
+        }
+
+        if self.grouped {
+            format!("({})", res)
+        } else {
+            res
+        }
+    }
+}
+
+/// The state of a query while it is rendered
+///
+/// Queries are rendered either with their values written out as literals or
+/// with a placeholder for each value (`$1`, `$2`, ... for [`Dialect::Postgres`],
+/// `?` for all others). In the latter case the values are
+/// recorded in the order of their placeholders, together with the column they
+/// belong to.
+///
+/// Parts of a query that the target [`Dialect`] does not support are still
+/// rendered, but recorded as errors that [`Context::finish`] reports.
+///
+/// [`Dialect`]: ./enum.Dialect.html
+/// [`Dialect::Postgres`]: ./enum.Dialect.html#variant.Postgres
+/// [`Context::finish`]: #method.finish
+struct Context<'a> {
+    dialect: Dialect,
+    placeholders: bool,
+    bound: Vec<(String, Value<'a>)>,
+    errors: Vec<QueryError>,
+    renames: Vec<(String, String)>,
+    renderer: Option<&'a dyn ValueRenderer>,
+    /// Whether text literals are escaped, see `SelectQuery::as_debug_string`
+    escape: bool,
+    /// The number of the first numbered placeholder
+    first_index: usize,
+    /// The case of the keywords, chosen by the outermost query
+    case: Option<KeywordCase>,
+}
+
+impl<'a> Context<'a> {
+    /// Writes all values out as literals
+    fn literal(dialect: Dialect) -> Context<'a> {
+        Context {
+            dialect,
+            placeholders: false,
+            bound: Vec::new(),
+            errors: Vec::new(),
+            renames: Vec::new(),
+            renderer: None,
+            escape: false,
+            first_index: 1,
+            case: None,
+        }
+    }
+
+    /// Writes all values out as literals, with text escaped for the dialect
+    fn escaped(dialect: Dialect) -> Context<'a> {
+        Context {
+            escape: true,
+            ..Context::literal(dialect)
+        }
+    }
+
+    /// Replaces all values with placeholders
+    fn placeholders(dialect: Dialect) -> Context<'a> {
+        Context {
+            placeholders: true,
+            ..Context::literal(dialect)
+        }
+    }
+
+    /// Numbers the placeholders from `first_index` on instead of 1
+    fn starting_at(self, first_index: usize) -> Context<'a> {
+        Context {
+            first_index,
+            ..self
+        }
+    }
+
+    /// Renders literals with `renderer` instead of the dialect, if there is one
+    fn rendered_by(self, renderer: Option<&'a dyn ValueRenderer>) -> Context<'a> {
+        Context {
+            renderer,
+            ..self
+        }
+    }
+
+    /// Writes the keywords in `case`, unless an enclosing query has chosen a case already
+    fn choose_case(&mut self, case: KeywordCase) {
+        if self.case.is_none() {
+            self.case = Some(case);
+        }
+    }
+
+    /// Returns the case the keywords are written in
+    fn case(&self) -> KeywordCase {
+        self.case.unwrap_or_default()
+    }
+
+    /// Returns `keywords` in the keyword case, see `KeywordCase::write`
+    fn kw<T: Display>(&self, keywords: T) -> String {
+        self.case().write(keywords)
+    }
+
+    /// Records an error unless the dialect is one of `dialects`
+    fn require(&mut self, feature: &'static str, dialects: &[Dialect]) {
+        if !dialects.contains(&self.dialect) {
+            self.errors.push(QueryError::Unsupported {
+                feature,
+                dialect: self.dialect,
+            });
+        }
+    }
+
+    /// Adds the table renames of a query, returning how many renames there were before
+    ///
+    /// Renames apply to subqueries as well, they are dropped again with `truncate`
+    /// once the query is rendered.
+    fn push_renames(&mut self, renames: &[(&str, &str)]) -> usize {
+        let before = self.renames.len();
+        self.renames.extend(renames.iter().map(|&(old, new)| (old.to_string(), new.to_string())));
+        before
+    }
+
+    /// Returns `name` with a renamed table, or the renamed table a column is qualified with, replaced
+    fn ident(&self, name: &str) -> String {
+        for (old, new) in &self.renames {
+            if name == old {
+                return new.clone();
+            }
+            if name.starts_with(old.as_str()) && name[old.len()..].starts_with('.') {
+                return format!("{}{}", new, &name[old.len()..]);
+            }
+        }
+        name.to_string()
+    }
+
+    /// Renders `names` as a comma separated list of identifiers
+    fn idents(&self, names: &[&str]) -> String {
+        let names: Vec<String> = names.iter().map(|n| self.ident(n)).collect();
+        names.join(", ")
+    }
+
+    /// Returns the SQL that stands for `value` of `column` in the query
+    fn value(&mut self, column: &str, value: &Value<'a>) -> String {
+        match *value {
+            Value::List(ref l) => {
+                /* every item of a list gets a placeholder of its own */
+                let items: Vec<String> = l.iter().map(|v| self.value(column, v)).collect();
+                format!("({})", items.join(", "))
+            },
+            /* these are part of the statement itself and never bound */
+            Value::Column(c) => self.ident(c),
+            Value::Interval(i) => format!("{} '{}'", self.kw("INTERVAL"), i),
+            Value::Default => self.kw("DEFAULT"),
+            /* the values of the subquery are bound where they appear */
+            Value::Subquery(ref q) => format!("({})", q.render(self)),
+            Value::Case(ref c) => c.render(column, self),
+            Value::Array(_) => {
+                self.require("ARRAY values", &[Dialect::Postgres]);
+                self.bind(column, value)
+            },
+            _ => self.bind(column, value),
+        }
+    }
+
+    /// Records an error if `value` is text of more than `max_length` characters
+    fn check_length(&mut self, column: &str, value: &Value, max_length: usize) {
+        let len = match *value {
+            Value::Varchar(v) => v.chars().count(),
+            Value::Text(ref t) => t.chars().count(),
+            _ => return,
+        };
+        if len > max_length {
+            self.errors.push(QueryError::TooLong {
+                column: column.to_string(),
+                max_length,
+            });
+        }
+    }
+
+    /// Returns the literal or the placeholder for `value`
+    fn bind(&mut self, column: &str, value: &Value<'a>) -> String {
+        if !self.placeholders {
+            if self.escape {
+                match *value {
+                    Value::Varchar(v) => return self.quote(v),
+                    Value::Text(ref t) => return self.quote(t),
+                    _ => {},
+                }
+            }
+            if let Value::Array(ref a) = *value {
+                let items: Vec<String> = a.iter().map(|v| self.bind(column, v)).collect();
+                return format!("{}[{}]", self.kw("ARRAY"), items.join(", "));
+            }
+            let literal = match self.renderer {
+                Some(r) => r.render(value),
+                None => self.dialect.render(value),
+            };
+            /* the renderer writes keyword literals in upper case, anything else is kept */
+            return match literal.as_str() {
+                "NULL" | "TRUE" | "FALSE" => self.kw(&literal),
+                _ => literal,
+            };
+        }
+
+        self.bound.push((column.to_string(), value.clone()));
+        match self.dialect {
+            Dialect::Postgres => format!("${}", self.first_index + self.bound.len() - 1),
+            _ => "?".to_string(),
+        }
+    }
+
+    /// Returns `text` as an escaped literal of the dialect
+    fn quote(&self, text: &str) -> String {
+        match self.dialect {
+            /* MySQL reads a backslash as an escape, it has to go first so the quotes stay doubled */
+            Dialect::MySql => format!("'{}'", text.replace('\\', "\\\\").replace('\'', "''")),
+            _ => format!("'{}'", text.replace('\'', "''")),
+        }
+    }
+
+    /// Returns the bound values in placeholder order
+    fn into_values(self) -> Vec<Value<'a>> {
+        self.bound.into_iter().map(|(_, v)| v).collect()
+    }
+
+    /// Returns `sql` unless an error was recorded while rendering it
+    fn finish(self, sql: String) -> Result<String, QueryError> {
+        match self.errors.into_iter().next() {
+            Some(e) => Err(e),
+            None => Ok(sql),
+        }
+    }
+}
+
+/// The trailing clauses of a query
+///
+/// Every query type hands its clauses to [`Clauses::append_to`] instead of
+/// formatting them itself, so that they are always emitted in the same
+/// canonical order:
+///
+/// `WHERE`, `GROUP BY`, `HAVING`, `ORDER BY`, `LIMIT`, `OFFSET`
+///
+/// Clauses a query type does not support are simply left empty.
+///
+/// [`Clauses::append_to`]: #method.append_to
+struct Clauses<'q, 'a: 'q> {
+    scope: &'q [WhereClause<'a>],
+    whre: &'q [(Condition, WhereClause<'a>)],
+    /// Condition trees that are AND-combined with the clauses of `whre`
+    conds: &'q [Cond<'a>],
+    group_by: Option<&'q GroupBy<'a>>,
+    having: &'q [(Condition, WhereClause<'a>)],
+    /// Conditions on the groups that are written as they are, after the ones of `having`
+    having_raw: &'q [&'a str],
+    order_by: Option<&'q OrderBy<'a>>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+    /// Whether the limit and offset get placeholders like values do
+    bind_limit: bool,
+    max_conditions: Option<usize>,
+}
+
+impl<'q, 'a> Clauses<'q, 'a> {
+    /// Returns the WHERE clause combining the scope and the user's conditions, if there are any
+    fn where_clause(&self, ctx: &mut Context<'a>) -> Option<String> {
+        if self.scope.is_empty() && self.whre.is_empty() && self.conds.is_empty() {
+            return None;
+        }
+
+        if let Some(max) = self.max_conditions {
+            let nested = self.conds.iter().flat_map(|c| c.clauses());
+            let whre = self.whre.iter().map(|e| &e.1);
+            let count = self.scope.iter().chain(whre).chain(nested).map(|c| match c.cond {
+                Value::List(ref l) if c.op == Operator::In || c.op == Operator::NotIn => l.len(),
+                _ => 1,
+            }).sum();
+            if count > max {
+                ctx.errors.push(QueryError::TooManyConditions { count, max });
+            }
+        }
+
+        let mut res = ctx.kw("WHERE ");
+        for (i, s) in self.scope.iter().enumerate() {
+            if i > 0 {
+                res.push_str(&ctx.kw(" AND "));
+            }
+            res.push_str(&s.render(ctx));
+        }
+
+        if !self.whre.is_empty() {
+            /* keep an OR in the user's conditions from escaping the scope */
+            let others = !self.scope.is_empty() || !self.conds.is_empty();
+            let group = others && self.whre[1..].iter().any(|e| e.0 == Condition::Or);
+
+            if !self.scope.is_empty() {
+                res.push_str(&ctx.kw(" AND "));
+            }
+            if group {
+                res.push('(');
+            }
+
+            res.push_str(&Clauses::combine(self.whre, ctx));
+
+            if group {
+                res.push(')');
+            }
+        }
+
+        let alone = self.scope.is_empty() && self.whre.is_empty() && self.conds.len() == 1;
+        for (i, c) in self.conds.iter().enumerate() {
+            if i > 0 || !self.scope.is_empty() || !self.whre.is_empty() {
+                res.push_str(&ctx.kw(" AND "));
+            }
+            if !alone && !c.grouped && c.has_or() {
+                res.push_str(&format!("({})", c.render(ctx)));
+            } else {
+                res.push_str(&c.render(ctx));
+            }
+        }
+
+        Some(res)
+    }
+
+    /// Combines `entries` from left to right, parenthesizing everything before a change of combiner
+    fn combine(entries: &[(Condition, WhereClause<'a>)], ctx: &mut Context<'a>) -> String {
+        let mut combined = entries[0].1.render(ctx);
+        for (i, (how, clause)) in entries[1..].iter().enumerate() {
+            /* `entries[i]` is the previous entry, its combiner only counts after the first */
+            if i > 0 && *how != entries[i].0 {
+                combined = format!("({})", combined);
+            }
+            combined.push_str(&format!(" {} ", ctx.kw(how)));
+            combined.push_str(&clause.render(ctx));
+        }
+        combined
+    }
+
+    /// Returns the limit or offset `n`, bound as the parameter `name` if requested
+    fn count(&self, name: &str, n: usize, ctx: &mut Context<'a>) -> String {
+        if self.bind_limit {
+            ctx.bind(name, &Value::UnsignedBigint(n as u64))
+        } else {
+            n.to_string()
+        }
+    }
+
+    /// Appends all non-empty clauses to `res` in canonical order
+    fn append_to(&self, res: &mut String, ctx: &mut Context<'a>) {
+        if let Some(w) = self.where_clause(ctx) {
+            res.push(' ');
+            res.push_str(&w);
+        }
+
+        if let Some(g) = self.group_by {
+            res.push(' ');
+            res.push_str(&g.render(ctx));
+        }
+
+        if !self.having.is_empty() {
+            /* keep an OR in the structured conditions from swallowing the raw ones */
+            let group = !self.having_raw.is_empty() && self.having[1..].iter().any(|e| e.0 == Condition::Or);
+            let combined = Clauses::combine(self.having, ctx);
+            if group {
+                res.push_str(&ctx.case().format(" HAVING ({})", &[&combined]));
+            } else {
+                res.push_str(&ctx.case().format(" HAVING {}", &[&combined]));
+            }
+        }
+        let alone = self.having.is_empty() && self.having_raw.len() == 1;
+        for (i, h) in self.having_raw.iter().enumerate() {
+            res.push_str(&ctx.kw(if i == 0 && self.having.is_empty() { " HAVING " } else { " AND " }));
+            if alone {
+                res.push_str(h);
+            } else {
+                /* an OR inside the condition must not bind to its neighbours */
+                res.push_str(&format!("({})", h));
+            }
+        }
+
+        if let Some(ob) = self.order_by {
+            res.push(' ');
+            res.push_str(&ob.render(ctx));
+        }
+
+        if ctx.dialect == Dialect::SqlServer && (self.limit.is_some() || self.offset.is_some()) {
+            /* SQL Server only knows OFFSET ... FETCH, which needs an ORDER BY */
+            if self.order_by.is_none() {
+                ctx.errors.push(QueryError::MissingOrderBy);
+            }
+            res.push_str(&ctx.kw(" OFFSET "));
+            res.push_str(&self.count("OFFSET", self.offset.unwrap_or(0), ctx));
+            res.push_str(&ctx.kw(" ROWS"));
+            if let Some(l) = self.limit {
+                res.push_str(&ctx.kw(" FETCH NEXT "));
+                res.push_str(&self.count("LIMIT", l, ctx));
+                res.push_str(&ctx.kw(" ROWS ONLY"));
+            }
+            return;
+        }
+
+        if let Some(l) = self.limit {
+            res.push_str(&ctx.kw(" LIMIT "));
+            res.push_str(&self.count("LIMIT", l, ctx));
+        }
+
+        if let Some(o) = self.offset {
+            res.push_str(&ctx.kw(" OFFSET "));
+            res.push_str(&self.count("OFFSET", o, ctx));
+        }
+    }
+}
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the ways to group the rows of a [`SelectQuery`]
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{SelectQuery, GroupBy, Aggregate, Dialect};
+///
+/// let mut q = SelectQuery::select(&["country", "city"]).from("sales");
+/// q.select_aggregate(Aggregate::sum("amount"));
+/// q.group_by(GroupBy::Rollup(&["country", "city"]));
+/// q.dialect(Dialect::Postgres);
+///
+/// assert_eq!(q.as_string(), "SELECT country, city, SUM(amount) FROM sales GROUP BY ROLLUP(country, city)");
+///
+/// q.dialect(Dialect::MySql);
+/// assert_eq!(q.as_string(), "SELECT country, city, SUM(amount) FROM sales GROUP BY country, city WITH ROLLUP");
+/// ```
+///
+/// Like with `ORDER BY`, a column can also be given by its position in the selected
+/// columns, starting at 1. Such an ordinal is checked against the number of selected
+/// columns when the query is validated, unless it selects `*` or starts with a raw statement.
+///
+/// ```
+/// use query_builder::{SelectQuery, GroupBy, Aggregate, QueryError};
+///
+/// let mut q = SelectQuery::select(&["country"]).from("sales");
+/// q.select_aggregate(Aggregate::sum("amount"));
+/// q.group_by(GroupBy::Columns(&["1"]));
+/// assert_eq!(q.as_string(), "SELECT country, SUM(amount) FROM sales GROUP BY 1");
+///
+/// q.group_by(GroupBy::Columns(&["3"]));
+/// assert_eq!(q.validate(), Err(QueryError::OrdinalOutOfRange { ordinal: 3, columns: 2 }));
+/// ```
+///
+/// [`SelectQuery`]: ./struct.SelectQuery.html
+pub enum GroupBy<'a> {
+    /// A plain `GROUP BY` of the given columns
+    Columns(&'a [&'a str]),
+    /// Subtotals for every prefix of the columns, plus a grand total
+    Rollup(&'a [&'a str]),
+    /// Subtotals for every combination of the columns
+    Cube(&'a [&'a str]),
+    /// One grouping for every set of columns, an empty set stands for the grand total
+    GroupingSets(&'a [&'a [&'a str]]),
+}
+
+impl<'a> GroupBy<'a> {
+    /// Renders the GROUP BY clause, recording in `ctx` if its dialect lacks support
+    fn render(&self, ctx: &mut Context) -> String {
+        match *self {
+            GroupBy::Columns(cols) => ctx.case().format("GROUP BY {}", &[&ctx.idents(cols)]),
+            GroupBy::Rollup(cols) => {
+                ctx.require("ROLLUP groupings", &[Dialect::MySql, Dialect::Postgres, Dialect::SqlServer]);
+                match ctx.dialect {
+                    Dialect::MySql => ctx.case().format("GROUP BY {} WITH ROLLUP", &[&ctx.idents(cols)]),
+                    _ => ctx.case().format("GROUP BY ROLLUP({})", &[&ctx.idents(cols)]),
+                }
+            },
+            GroupBy::Cube(cols) => {
+                ctx.require("CUBE groupings", &[Dialect::Postgres, Dialect::SqlServer]);
+                ctx.case().format("GROUP BY CUBE({})", &[&ctx.idents(cols)])
+            },
+            GroupBy::GroupingSets(sets) => {
+                ctx.require("GROUPING SETS", &[Dialect::Postgres, Dialect::SqlServer]);
+                let sets: Vec<String> = sets.iter().map(|s| format!("({})", ctx.idents(s))).collect();
+                ctx.case().format("GROUP BY GROUPING SETS({})", &[&sets.join(", ")])
+            },
+        }
+    }
+
+    /// Returns all columns grouped by, in the order they are given
+    fn columns(&self) -> Vec<&'a str> {
+        match *self {
+            GroupBy::Columns(cols) | GroupBy::Rollup(cols) | GroupBy::Cube(cols) => cols.to_vec(),
+            GroupBy::GroupingSets(sets) => sets.iter().flat_map(|s| s.iter().cloned()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the aggregate functions of SQL
+pub enum AggregateFunction {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+impl Display for AggregateFunction {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            AggregateFunction::Count    => write!(f, "COUNT"),
+            AggregateFunction::Sum      => write!(f, "SUM"),
+            AggregateFunction::Avg      => write!(f, "AVG"),
+            AggregateFunction::Min      => write!(f, "MIN"),
+            AggregateFunction::Max      => write!(f, "MAX"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+/// Struct representing a call of an aggregate function like `COUNT(*)`
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::Aggregate;
+///
+/// assert_eq!(Aggregate::count("*").as_string(), "COUNT(*)");
+/// assert_eq!(Aggregate::count("user_id").distinct().as_string(), "COUNT(DISTINCT user_id)");
+/// ```
+pub struct Aggregate<'a> {
+    function: AggregateFunction,
+    column: &'a str,
+    /// Further columns of a distinct count, see `Aggregate::count_distinct`
+    more: &'a [&'a str],
+    distinct: bool,
+}
+
+impl<'a> Aggregate<'a> {
+    /// Creates a new call of `function` over `column`
+    pub fn new(function: AggregateFunction, column: &'a str) -> Aggregate<'a> {
+        Aggregate {
+            function,
+            column,
+            more: &[],
+            distinct: false,
+        }
+    }
+
+    /// Creates a count of the distinct combinations of `columns`
+    ///
+    /// Counting more than one column like this is only supported by MySQL, the
+    /// queries of other dialects fail to render with [`QueryError::Unsupported`].
+    /// Without any columns the query fails to render with [`QueryError::EmptyColumns`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{Aggregate, SelectQuery, Dialect};
+    ///
+    /// let mut query = SelectQuery::select(&[]).from("visits");
+    /// query.select_aggregate(Aggregate::count_distinct(&["user_id", "day"]));
+    /// query.dialect(Dialect::MySql);
+    ///
+    /// assert_eq!(query.as_string(), "SELECT COUNT(DISTINCT user_id, day) FROM visits");
+    /// ```
+    ///
+    /// [`QueryError::Unsupported`]: ./enum.QueryError.html#variant.Unsupported
+    /// [`QueryError::EmptyColumns`]: ./enum.QueryError.html#variant.EmptyColumns
+    pub fn count_distinct(columns: &'a [&'a str]) -> Aggregate<'a> {
+        Aggregate {
+            column: columns.first().cloned().unwrap_or("*"),
+            more: columns.get(1..).unwrap_or(&[]),
+            ..Aggregate::count("*").distinct()
+        }
+    }
+
+    /// Shorthand for `Aggregate::new(AggregateFunction::Count, column)`
+    pub fn count(column: &'a str) -> Aggregate<'a> {
+        Aggregate::new(AggregateFunction::Count, column)
+    }
+
+    /// Shorthand for `Aggregate::new(AggregateFunction::Sum, column)`
+    pub fn sum(column: &'a str) -> Aggregate<'a> {
+        Aggregate::new(AggregateFunction::Sum, column)
+    }
+
+    /// Shorthand for `Aggregate::new(AggregateFunction::Avg, column)`
+    pub fn avg(column: &'a str) -> Aggregate<'a> {
+        Aggregate::new(AggregateFunction::Avg, column)
+    }
+
+    /// Shorthand for `Aggregate::new(AggregateFunction::Min, column)`
+    pub fn min(column: &'a str) -> Aggregate<'a> {
+        Aggregate::new(AggregateFunction::Min, column)
+    }
+
+    /// Shorthand for `Aggregate::new(AggregateFunction::Max, column)`
+    pub fn max(column: &'a str) -> Aggregate<'a> {
+        Aggregate::new(AggregateFunction::Max, column)
+    }
+
+    /// Makes the function only consider distinct values of the column
+    ///
+    /// This is independent of whether the query itself selects distinct rows.
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
+
+    /// Returns the [`String`] representation of the function call
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn as_string(&self) -> String {
+        self.render(&mut Context::literal(Dialect::Generic))
+    }
+
+    /// Renders the function call, with the tables of the columns renamed if `ctx` says so
+    fn render(&self, ctx: &mut Context) -> String {
+        /* `DISTINCT *` is not valid SQL */
+        if self.distinct && self.column == "*" {
+            ctx.errors.push(QueryError::EmptyColumns);
+        }
+        let mut columns = ctx.ident(self.column);
+        if !self.more.is_empty() {
+            ctx.require("COUNT(DISTINCT) of several columns", &[Dialect::MySql]);
+            columns.push_str(", ");
+            columns.push_str(&ctx.idents(self.more));
+        }
+
+        if self.distinct {
+            ctx.case().format("{}(DISTINCT {})", &[&ctx.kw(self.function), &columns])
+        } else {
+            format!("{}({})", ctx.kw(self.function), columns)
+        }
+    }
+}
+
+impl<'a> Display for Aggregate<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the kinds of JOINs
+pub enum JoinType {
+    Inner,
+    Left,
+    Right,
+    Full,
+    Cross,
+}
+
+impl Display for JoinType {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            JoinType::Inner     => write!(f, "INNER JOIN"),
+            JoinType::Left      => write!(f, "LEFT JOIN"),
+            JoinType::Right     => write!(f, "RIGHT JOIN"),
+            JoinType::Full      => write!(f, "FULL OUTER JOIN"),
+            JoinType::Cross     => write!(f, "CROSS JOIN"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the ways to state which rows of a [`Join`] belong together
+///
+/// [`Join`]: ./struct.Join.html
+pub enum JoinCondition<'a> {
+    /// `ON` followed by the given condition
+    On(&'a str),
+    /// `ON` followed by the equality of the two given columns, see [`Join::on_eq`]
+    ///
+    /// [`Join::on_eq`]: ./struct.Join.html#method.on_eq
+    OnEq(&'a str, &'a str),
+    /// `USING` followed by the given columns that both tables share
+    Using(&'a [&'a str]),
+}
+
+impl<'a> Display for JoinCondition<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            JoinCondition::On(c)        => write!(f, "ON {}", c),
+            JoinCondition::OnEq(l, r)   => write!(f, "ON {} = {}", l, r),
+            JoinCondition::Using(cols)  => write!(f, "USING ({})", cols.join(", ")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Struct representing a JOIN of another table in a [`SelectQuery`]
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{SelectQuery, Join};
+///
+/// let mut q = SelectQuery::select(&["*"]).from("users");
+/// q.join(Join::left("orders").using(&["user_id"]));
+///
+/// assert_eq!(q.as_string(), "SELECT * FROM users LEFT JOIN orders USING (user_id)");
+/// ```
+///
+/// [`SelectQuery`]: ./struct.SelectQuery.html
+pub struct Join<'a> {
+    kind: JoinType,
+    table: &'a str,
+    /// The derived table joined under the name `table`, if any
+    subquery: Option<Box<SelectQuery<'a>>>,
+    condition: Option<JoinCondition<'a>>,
+}
+
+impl<'a> Join<'a> {
+    /// Creates a new [`Join`] of kind `kind` with `table` but without any condition
+    ///
+    /// [`Join`]: ./struct.Join.html
+    pub fn new(kind: JoinType, table: &'a str) -> Join<'a> {
+        Join {
+            kind,
+            table,
+            subquery: None,
+            condition: None,
+        }
+    }
+
+    /// Creates a new [`Join`] of kind `kind` with the result of `query`, which is referred to as `alias`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Join, JoinType};
+    ///
+    /// let totals = SelectQuery::select(&["user_id", "SUM(amount) AS total"]).from("orders");
+    /// let join = Join::subquery(JoinType::Inner, totals, "t").on("t.user_id = users.id");
+    ///
+    /// assert_eq!(
+    ///     join.as_string(),
+    ///     "INNER JOIN (SELECT user_id, SUM(amount) AS total FROM orders) AS t ON t.user_id = users.id"
+    /// );
+    /// ```
+    ///
+    /// [`Join`]: ./struct.Join.html
+    pub fn subquery(kind: JoinType, query: SelectQuery<'a>, alias: &'a str) -> Join<'a> {
+        Join {
+            subquery: Some(Box::new(query)),
+            ..Join::new(kind, alias)
+        }
+    }
+
+    /// Shorthand for `Join::new(JoinType::Inner, table)`
+    pub fn inner(table: &'a str) -> Join<'a> {
+        Join::new(JoinType::Inner, table)
+    }
+
+    /// Shorthand for `Join::new(JoinType::Left, table)`
+    pub fn left(table: &'a str) -> Join<'a> {
+        Join::new(JoinType::Left, table)
+    }
+
+    /// Shorthand for `Join::new(JoinType::Right, table)`
+    pub fn right(table: &'a str) -> Join<'a> {
+        Join::new(JoinType::Right, table)
+    }
+
+    /// Shorthand for `Join::new(JoinType::Full, table)`
+    pub fn full(table: &'a str) -> Join<'a> {
+        Join::new(JoinType::Full, table)
+    }
+
+    /// Shorthand for `Join::new(JoinType::Cross, table)`
+    pub fn cross(table: &'a str) -> Join<'a> {
+        Join::new(JoinType::Cross, table)
+    }
+
+    /// Sets the condition of the join to `ON condition`
+    pub fn on(mut self, condition: &'a str) -> Self {
+        self.condition = Some(JoinCondition::On(condition));
+        self
+    }
+
+    /// Sets the condition of the join to `ON left = right`, the most common way of joining
+    ///
+    /// Unlike with [`on`], the columns are renamed along with their tables by
+    /// [`SelectQuery::rename_table`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::Join;
+    ///
+    /// let join = Join::inner("orders").on_eq("users.id", "orders.user_id");
+    ///
+    /// assert_eq!(join.as_string(), "INNER JOIN orders ON users.id = orders.user_id");
+    /// ```
+    ///
+    /// [`on`]: #method.on
+    /// [`SelectQuery::rename_table`]: ./struct.SelectQuery.html#method.rename_table
+    pub fn on_eq(mut self, left: &'a str, right: &'a str) -> Self {
+        self.condition = Some(JoinCondition::OnEq(left, right));
+        self
+    }
+
+    /// Sets the condition of the join to `USING (columns)`
+    pub fn using(mut self, columns: &'a [&'a str]) -> Self {
+        self.condition = Some(JoinCondition::Using(columns));
+        self
+    }
+
+    /// Returns the [`String`] representation of the [`Join`]
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::Join;
+    ///
+    /// let join = Join::inner("orders").on("users.id = orders.user_id");
+    ///
+    /// assert_eq!(join.as_string(), "INNER JOIN orders ON users.id = orders.user_id");
+    /// ```
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`Join`]: ./struct.Join.html
+    pub fn as_string(&self) -> String {
+        self.render(&mut Context::literal(Dialect::Generic))
+    }
+}
+
+impl<'a> Join<'a> {
+    /// Renders the join like `as_string`, with the table renamed if `ctx` says so
+    fn render(&self, ctx: &mut Context<'a>) -> String {
+        let table = match self.subquery {
+            Some(ref q) => ctx.case().format("({}) AS {}", &[&q.render(ctx), &self.table]),
+            None => ctx.ident(self.table),
+        };
+
+        let kind = ctx.kw(self.kind);
+        match self.condition {
+            Some(JoinCondition::OnEq(l, r)) => ctx.case().format("{} {} ON {} = {}", &[&kind, &table, &ctx.ident(l), &ctx.ident(r)]),
+            Some(JoinCondition::On(c)) => ctx.case().format("{} {} ON {}", &[&kind, &table, &c]),
+            Some(JoinCondition::Using(cols)) => ctx.case().format("{} {} USING ({})", &[&kind, &table, &cols.join(", ")]),
+            None => format!("{} {}", kind, table),
+        }
+    }
+}
+
+impl<'a> Display for Join<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Something a [`SelectQuery`] selects
+///
+/// [`SelectQuery`]: ./struct.SelectQuery.html
+enum SelectItem<'a> {
+    Column(&'a str),
+    Aggregate(Aggregate<'a>),
+    /// The number of rows of the whole result, see `SelectQuery::count_over`
+    CountOver,
+    /// An expression written as it is, see `SelectQuery::select_raw`
+    Raw(&'a str),
+    /// An expression written as it is, along with the name it is returned as
+    Aliased(&'a str, &'a str),
+    /// `COALESCE(left, right) AS alias`, see `SelectQuery::full_join_coalesced`
+    Coalesce(&'a str, &'a str, &'a str),
+}
+
+impl<'a> SelectItem<'a> {
+    fn render(&self, ctx: &mut Context) -> String {
+        match *self {
+            SelectItem::Column(c) => ctx.ident(c),
+            SelectItem::Aggregate(ref a) => a.render(ctx),
+            SelectItem::CountOver => ctx.kw("COUNT(*) OVER () AS total_count"),
+            SelectItem::Raw(r) => r.to_string(),
+            SelectItem::Aliased(e, a) => ctx.case().format("{} AS {}", &[&e, &a]),
+            SelectItem::Coalesce(l, r, a) => ctx.case().format("COALESCE({}, {}) AS {}", &[&ctx.ident(l), &ctx.ident(r), &a]),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Something a [`SelectQuery`] can select from
+///
+/// [`SelectQuery`]: ./struct.SelectQuery.html
+enum FromItem<'a> {
+    Table(&'a str),
+    Subquery(Box<SelectQuery<'a>>),
+    Values(ValuesQuery<'a>),
+    /// A raw call of a table-valued function and its alias, see `SelectQuery::from_function`
+    Function(&'a str, &'a str),
+}
+
+impl<'a> FromItem<'a> {
+    fn render(&self, ctx: &mut Context<'a>) -> String {
+        match *self {
+            FromItem::Table(t) => t.to_string(),
+            FromItem::Subquery(ref q) => match q.alias {
+                Some(a) => ctx.case().format("({}) AS {}", &[&q.render(ctx), &a]),
+                None => format!("({})", q.render(ctx)),
+            },
+            FromItem::Values(ref v) => match v.alias {
+                Some((a, cols)) => ctx.case().format("({}) AS {} ({})", &[&v.render(ctx), &a, &cols.join(", ")]),
+                None => format!("({})", v.render(ctx)),
+            },
+            FromItem::Function(call, alias) => ctx.case().format("{} AS {}", &[&call, &alias]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the sampling methods of `TABLESAMPLE`
+pub enum SampleMethod {
+    /// Samples whole pages of the table, fast but less random
+    System,
+    /// Samples each row individually, only supported by Postgres
+    Bernoulli,
+}
+
+impl Display for SampleMethod {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            SampleMethod::System    => write!(f, "SYSTEM"),
+            SampleMethod::Bernoulli => write!(f, "BERNOULLI"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the hints telling the database how to execute a [`SelectQuery`]
+///
+/// [`SelectQuery`]: ./struct.SelectQuery.html
+pub enum Hint<'a> {
+    /// An optimizer hint like `INDEX(t idx)`, rendered as `/*+ ... */` after `SELECT`
+    ///
+    /// MySQL and Oracle understand these, all other databases treat them as a comment.
+    Optimizer(&'a str),
+    /// Only uses one of the indexes to find the rows of the first table, only supported by MySQL
+    UseIndex(&'a [&'a str]),
+    /// Uses one of the indexes even if a table scan seems cheaper, only supported by MySQL
+    ForceIndex(&'a [&'a str]),
+    /// Does not use the indexes to find the rows of the first table, only supported by MySQL
+    IgnoreIndex(&'a [&'a str]),
+}
+
+impl<'a> Hint<'a> {
+    /// Renders an index hint, which follows the first table of the FROM clause
+    fn render_index(&self, ctx: &mut Context) -> Option<String> {
+        let (keyword, indexes) = match *self {
+            Hint::Optimizer(_) => return None,
+            Hint::UseIndex(i)    => ("USE", i),
+            Hint::ForceIndex(i)  => ("FORCE", i),
+            Hint::IgnoreIndex(i) => ("IGNORE", i),
+        };
+        ctx.require("index hints", &[Dialect::MySql]);
+        Some(ctx.case().format("{} INDEX ({})", &[&ctx.kw(keyword), &indexes.join(", ")]))
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Struct representing a SQL-INSERT Query
+/// A simple query to select everything from a table can be created like this:
+/// 
+/// ## Example 
+/// 
+/// ```
+/// use query_builder::SelectQuery;
+///
+/// // create the query
+/// let query = SelectQuery::select(&["*"]).from("users");
+///
+/// // make sure it looks like you would expect it to look
+/// assert_eq!(query.as_string(), "SELECT * FROM users");
+/// ```
+pub struct SelectQuery<'a> {
+    select: Vec<SelectItem<'a>>,
+    from: Vec<FromItem<'a>>,
+    joins: Vec<Join<'a>>,
+    alias: Option<&'a str>,
+    pub whre: WhereList<'a>,
+    scope: Vec<WhereClause<'a>>,
+    conds: Vec<Cond<'a>>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+    bind_limit: bool,
+    distinct: bool,
+    order_by: Option<OrderBy<'a>>,
+    group_by: Option<GroupBy<'a>>,
+    having: WhereList<'a>,
+    having_raw: Vec<&'a str>,
+    tablesample: Option<(SampleMethod, u8)>,
+    hints: Vec<Hint<'a>>,
+    raw: Option<&'a str>,
+    table_shorthand: bool,
+    only: bool,
+    partitions: Vec<&'a str>,
+    renames: Vec<(&'a str, &'a str)>,
+    allowed_columns: Option<Vec<&'a str>>,
+    max_conditions: Option<usize>,
+    keyword_case: KeywordCase,
+    renderer: Option<&'a dyn ValueRenderer>,
+    dialect: Dialect,
+}
+
+impl<'a> Display for SelectQuery<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+/* queries are compared by the SQL they render to, this allows them to be used as a Value */
+impl<'a> PartialEq for SelectQuery<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for SelectQuery<'a> {}
+
+impl<'a> PartialOrd for SelectQuery<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for SelectQuery<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.dialect, self.as_string()).cmp(&(other.dialect, other.as_string()))
+    }
+}
+
+
+impl<'a> SelectQuery<'a> {
+    /// Creates a new [`SelectQuery`] that selects data from the row/s `rows`
+    ///
+    /// [`SelectQuery`]: ./struct.SelectQuery.html
+    pub fn select(rows: &[&'a str]) -> SelectQuery<'a> {
+        SelectQuery {
+            select: rows.iter().map(|r| SelectItem::Column(r)).collect(),
+            from: Vec::new(),
+            joins: Vec::new(),
+            alias: None,
+            whre: WhereList::new(),
+            scope: Vec::new(),
+            conds: Vec::new(),
+            limit: None,
+            offset: None,
+            bind_limit: false,
+            distinct: false,
+            order_by: None,
+            group_by: None,
+            having: WhereList::new(),
+            having_raw: Vec::new(),
+            tablesample: None,
+            hints: Vec::new(),
+            raw: None,
+            table_shorthand: false,
+            only: false,
+            partitions: Vec::new(),
+            renames: Vec::new(),
+            allowed_columns: None,
+            max_conditions: None,
+            keyword_case: KeywordCase::Upper,
+            renderer: None,
+            dialect: Dialect::Generic,
+        }
+    }
+
+    /// Creates a new [`SelectQuery`] that starts with the hand-written SQL `sql`
+    ///
+    /// The WHERE, GROUP BY, ORDER BY, LIMIT and OFFSET clauses of the query are appended
+    /// to `sql`, which replaces everything up to them. This allows moving queries over
+    /// to the builder one part at a time. `sql` is used as it is, it must not come
+    /// from user input.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause};
+    ///
+    /// let mut query = SelectQuery::from_raw("SELECT u.name, COUNT(*) FROM users u JOIN posts p ON p.author = u.id");
+    /// query.whre.push(WhereClause::new("u.active", Value::Bool(true)));
+    ///
+    /// assert_eq!(
+    ///     query.as_string(),
+    ///     "SELECT u.name, COUNT(*) FROM users u JOIN posts p ON p.author = u.id WHERE u.active = TRUE"
+    /// );
+    /// ```
+    ///
+    /// [`SelectQuery`]: ./struct.SelectQuery.html
+    pub fn from_raw(sql: &'a str) -> SelectQuery<'a> {
+        SelectQuery {
+            raw: Some(sql),
+            ..SelectQuery::select(&[])
+        }
+    }
+
+    /// Sets the table to select from to the value of `t`
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let q = SelectQuery::select(&["user"]).from("users");
+    ///
+    /// assert_eq!(q.as_string(), "SELECT user FROM users")
+    /// ```
+    pub fn from(mut self, t: &'a str) -> Self {
+        self.from = vec![FromItem::Table(t)];
+        self
+    }
+
+    /// Sets the tables to select from to `tables`, which are joined implicitly
+    ///
+    /// The join conditions have to be added as [`WhereClause`]s.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause};
+    ///
+    /// let mut q = SelectQuery::select(&["*"]).from_tables(&["users", "orders"]);
+    /// q.whre.push(WhereClause::new("users.id", Value::Column("orders.user_id")));
+    ///
+    /// assert_eq!(q.as_string(), "SELECT * FROM users, orders WHERE users.id = orders.user_id")
+    /// ```
+    ///
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    pub fn from_tables(mut self, tables: &[&'a str]) -> Self {
+        self.from = tables.iter().map(|t| FromItem::Table(t)).collect();
+        self
+    }
+
+    /// Sets the query to select from the result of the [`SelectQuery`] `q`
+    ///
+    /// The subquery is referred to by its [`alias`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let sub = SelectQuery::select(&["name"]).from("users").alias("u");
+    /// let q = SelectQuery::select(&["u.name"]).from_subquery(sub);
+    ///
+    /// assert_eq!(q.as_string(), "SELECT u.name FROM (SELECT name FROM users) AS u")
+    /// ```
+    ///
+    /// [`SelectQuery`]: ./struct.SelectQuery.html
+    /// [`alias`]: #method.alias
+    pub fn from_subquery(mut self, q: SelectQuery<'a>) -> Self {
+        self.from = vec![FromItem::Subquery(Box::new(q))];
+        self
+    }
+
+    /// Sets the query to select from the rows of the [`ValuesQuery`] `v`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, ValuesQuery, Value};
+    ///
+    /// let mut v = ValuesQuery::new().alias("t", &["id"]);
+    /// v.row(vec![Value::Int(1)]);
+    /// let q = SelectQuery::select(&["t.id"]).from_values(v);
+    ///
+    /// assert_eq!(q.as_string(), "SELECT t.id FROM (VALUES (1)) AS t (id)")
+    /// ```
+    ///
+    /// [`ValuesQuery`]: ./struct.ValuesQuery.html
+    pub fn from_values(mut self, v: ValuesQuery<'a>) -> Self {
+        self.from = vec![FromItem::Values(v)];
+        self
+    }
+
+    /// Sets the query to select from the rows returned by the function call `call`, referred to as `alias`
+    ///
+    /// The call is written into the query as it is, so it must never contain user input.
+    /// Pass values as literals the database can check instead.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let q = SelectQuery::select(&["t.n"]).from_function("generate_series(1, 10)", "t(n)");
+    ///
+    /// assert_eq!(q.as_string(), "SELECT t.n FROM generate_series(1, 10) AS t(n)")
+    /// ```
+    pub fn from_function(mut self, call: &'a str, alias: &'a str) -> Self {
+        self.from = vec![FromItem::Function(call, alias)];
+        self
+    }
+
+    /// Selects only a random sample of about `percent` percent of the rows of the table
+    ///
+    /// The `TABLESAMPLE` clause is rendered after the first table of the FROM clause.
+    /// It is only supported by Postgres and SQL Server, the latter only knows
+    /// [`SampleMethod::System`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, SampleMethod, Dialect};
+    ///
+    /// let mut q = SelectQuery::select(&["*"]).from("events");
+    /// q.tablesample(SampleMethod::System, 10);
+    /// q.dialect(Dialect::Postgres);
+    ///
+    /// assert_eq!(q.as_string(), "SELECT * FROM events TABLESAMPLE SYSTEM (10)");
+    ///
+    /// q.dialect(Dialect::SqlServer);
+    /// assert_eq!(q.as_string(), "SELECT * FROM events TABLESAMPLE SYSTEM (10 PERCENT)");
+    /// ```
+    ///
+    /// [`SampleMethod::System`]: ./enum.SampleMethod.html#variant.System
+    pub fn tablesample(&mut self, method: SampleMethod, percent: u8) {
+        self.tablesample = Some((method, percent));
+    }
+
+    /// Adds a [`Hint`] on how to execute the query
+    ///
+    /// Optimizer hints are rendered after `SELECT`, index hints after the first
+    /// table of the FROM clause.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Hint, Dialect};
+    ///
+    /// let mut q = SelectQuery::select(&["*"]).from("users");
+    /// q.hint(Hint::Optimizer("INDEX(users idx_name)"));
+    /// q.hint(Hint::UseIndex(&["idx_name"]));
+    /// q.dialect(Dialect::MySql);
+    ///
+    /// assert_eq!(q.as_string(), "SELECT /*+ INDEX(users idx_name) */ * FROM users USE INDEX (idx_name)");
+    /// ```
+    ///
+    /// [`Hint`]: ./enum.Hint.html
+    pub fn hint(&mut self, hint: Hint<'a>) {
+        self.hints.push(hint);
+    }
+
+    /// Sets the alias the query is referred to by when it is used as a subquery
+    ///
+    /// The alias is ignored when the query is rendered on its own.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let q = SelectQuery::select(&["name"]).from("users").alias("u");
+    ///
+    /// assert_eq!(q.as_string(), "SELECT name FROM users")
+    /// ```
+    pub fn alias(mut self, alias: &'a str) -> Self {
+        self.alias = Some(alias);
+        self
+    }
+
+    /// Adds the [`Aggregate`] `a` to the selected values
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Aggregate};
+    ///
+    /// let mut q = SelectQuery::select(&["country"]).from("users");
+    /// q.select_aggregate(Aggregate::count("*"));
+    ///
+    /// assert_eq!(q.as_string(), "SELECT country, COUNT(*) FROM users")
+    /// ```
+    ///
+    /// [`Aggregate`]: ./struct.Aggregate.html
+    pub fn select_aggregate(&mut self, a: Aggregate<'a>) {
+        self.select.push(SelectItem::Aggregate(a));
+    }
+
+    /// Adds the total number of rows matching the query, ignoring the limit and offset,
+    /// to the selected values as `total_count`
+    ///
+    /// Every row of a page then carries the total, so a pagination UI does not need a
+    /// second query to count the rows. This uses a window function, which requires
+    /// Postgres, SQL Server, MySQL 8 or SQLite 3.25 or newer.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let mut q = SelectQuery::select(&["name"]).from("users");
+    /// q.count_over();
+    /// q.paginate(2, 10).unwrap();
+    ///
+    /// assert_eq!(q.as_string(), "SELECT name, COUNT(*) OVER () AS total_count FROM users LIMIT 10 OFFSET 10")
+    /// ```
+    pub fn count_over(&mut self) {
+        self.select.push(SelectItem::CountOver);
+    }
+
+    /// Adds the expression `expr` to the selected values, e.g. a function call
+    ///
+    /// Unlike the columns given to [`select`], the expression is written as it is and
+    /// not affected by table renames. It must not come from user input.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let mut q = SelectQuery::select(&["id"]).from("orders");
+    /// q.select_raw("to_char(created, 'YYYY-MM') AS month");
+    ///
+    /// assert_eq!(q.as_string(), "SELECT id, to_char(created, 'YYYY-MM') AS month FROM orders")
+    /// ```
+    ///
+    /// [`select`]: #method.select
+    pub fn select_raw(&mut self, expr: &'a str) {
+        self.select.push(SelectItem::Raw(expr));
+    }
+
+    /// Adds the [`Join`] `j` to the query
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Join};
+    ///
+    /// let mut q = SelectQuery::select(&["*"]).from("users");
+    /// q.join(Join::inner("orders").on("users.id = orders.user_id"));
+    ///
+    /// assert_eq!(q.as_string(), "SELECT * FROM users INNER JOIN orders ON users.id = orders.user_id")
+    /// ```
+    ///
+    /// [`Join`]: ./struct.Join.html
+    pub fn join(&mut self, j: Join<'a>) {
+        self.joins.push(j);
+    }
+
+    /// Joins the result of `query` as the derived table `alias`, matching rows by `condition`
+    ///
+    /// This is a shorthand for [`Join::subquery`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, JoinType, JoinCondition};
+    ///
+    /// let latest = SelectQuery::select(&["user_id", "MAX(created) AS created"]).from("logins");
+    ///
+    /// let mut q = SelectQuery::select(&["users.name", "l.created"]).from("users");
+    /// q.join_subquery(JoinType::Left, latest, "l", JoinCondition::On("l.user_id = users.id"));
+    ///
+    /// assert_eq!(
+    ///     q.as_string(),
+    ///     "SELECT users.name, l.created FROM users \
+    ///      LEFT JOIN (SELECT user_id, MAX(created) AS created FROM logins) AS l ON l.user_id = users.id"
+    /// );
+    /// ```
+    ///
+    /// [`Join::subquery`]: ./struct.Join.html#method.subquery
+    pub fn join_subquery(&mut self, kind: JoinType, query: SelectQuery<'a>, alias: &'a str, condition: JoinCondition<'a>) {
+        self.joins.push(Join {
+            condition: Some(condition),
+            ..Join::subquery(kind, query, alias)
+        });
+    }
+
+    /// Adds a `FULL OUTER JOIN` with `table` on `left = right` and selects the joined key as `alias`
+    ///
+    /// Rows only found in one of the tables have a `NULL` key on the other side, so the key is
+    /// selected as `COALESCE(left, right) AS alias` to always have a value.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let mut q = SelectQuery::select(&[]).from("plans");
+    /// q.full_join_coalesced("usage", "plans.user_id", "usage.user_id", "user_id");
+    ///
+    /// assert_eq!(
+    ///     q.as_string(),
+    ///     "SELECT COALESCE(plans.user_id, usage.user_id) AS user_id FROM plans \
+    ///      FULL OUTER JOIN usage ON plans.user_id = usage.user_id"
+    /// );
+    /// ```
+    pub fn full_join_coalesced(&mut self, table: &'a str, left: &'a str, right: &'a str, alias: &'a str) {
+        self.select.push(SelectItem::Coalesce(left, right, alias));
+        self.joins.push(Join::full(table).on_eq(left, right));
+    }
+
+    /// Restricts the query to the named table itself, excluding tables inheriting from it
+    ///
+    /// This renders the `ONLY` keyword before the table, which is only supported by Postgres.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Dialect};
+    ///
+    /// let mut query = SelectQuery::select(&["*"]).from("cities");
+    /// query.only();
+    /// query.dialect(Dialect::Postgres);
+    ///
+    /// assert_eq!(query.as_string(), "SELECT * FROM ONLY cities");
+    /// ```
+    pub fn only(&mut self) {
+        self.only = true;
+    }
+
+    /// Restricts the query to the `partitions` of a partitioned table
+    ///
+    /// This renders a `PARTITION` clause after the table, which is only supported by MySQL.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Dialect};
+    ///
+    /// let mut query = SelectQuery::select(&["*"]).from("logs");
+    /// query.partition(&["p0", "p1"]);
+    /// query.dialect(Dialect::MySql);
+    ///
+    /// assert_eq!(query.as_string(), "SELECT * FROM logs PARTITION (p0, p1)");
+    /// ```
+    pub fn partition(&mut self, partitions: &[&'a str]) {
+        self.partitions = partitions.to_vec();
+    }
+
+    /// Makes the query return every distinct row only once
+    ///
+    /// Databases like Postgres reject a `SELECT DISTINCT` that is ordered by something
+    /// it does not select, [`try_as_string`] and [`validate`] report this as an error.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, OrderBy, QueryError};
+    ///
+    /// let mut query = SelectQuery::select(&["city"]).from("users");
+    /// query.distinct();
+    /// query.order_by(OrderBy::Row("city"));
+    /// assert_eq!(query.try_as_string(), Ok("SELECT DISTINCT city FROM users ORDER BY city".to_string()));
+    ///
+    /// query.order_by(OrderBy::Row("name"));
+    /// assert_eq!(query.validate(), Err(QueryError::OrderNotSelected { column: "name".to_string() }));
+    /// ```
+    ///
+    /// [`try_as_string`]: #method.try_as_string
+    /// [`validate`]: #method.validate
+    pub fn distinct(&mut self) {
+        self.distinct = true;
+    }
+
+    /// Renders a query for all rows of a table as `TABLE name` for Postgres
+    ///
+    /// The shorthand is only used if the query selects `*` from a single table without
+    /// any joins, conditions or groupings. An ORDER BY, a limit and an offset are fine.
+    /// Every other query, and every other dialect, gets the full `SELECT` statement.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Dialect};
+    ///
+    /// let mut query = SelectQuery::select(&["*"]).from("users");
+    /// query.table_shorthand();
+    /// assert_eq!(query.as_string(), "SELECT * FROM users");
+    ///
+    /// query.dialect(Dialect::Postgres);
+    /// assert_eq!(query.as_string(), "TABLE users");
+    /// ```
+    pub fn table_shorthand(&mut self) {
+        self.table_shorthand = true;
+    }
+
+    /// Returns the query wrapped in an `EXPLAIN` statement, for looking into how the database runs it
+    ///
+    /// The prefix depends on the [`ExplainMode`] and the dialect of the query, see there.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, ExplainMode, Dialect};
+    ///
+    /// let mut query = SelectQuery::select(&["*"]).from("users");
+    /// assert_eq!(query.explain(ExplainMode::Plain).as_string(), "EXPLAIN SELECT * FROM users");
+    ///
+    /// query.dialect(Dialect::MySql);
+    /// assert_eq!(query.explain(ExplainMode::Json).as_string(), "EXPLAIN FORMAT=JSON SELECT * FROM users");
+    /// ```
+    ///
+    /// [`ExplainMode`]: ./enum.ExplainMode.html
+    pub fn explain(&self, mode: ExplainMode) -> ExplainQuery<'_, 'a> {
+        ExplainQuery {
+            query: self,
+            mode,
+        }
+    }
+
+    /// Returns the table to render as `TABLE name`, if the shorthand can be used for `dialect`
+    fn shorthand_table(&self, dialect: Dialect) -> Option<&'a str> {
+        let plain = self.table_shorthand && dialect == Dialect::Postgres
+            && !self.distinct && self.joins.is_empty() && self.hints.is_empty()
+            && self.partitions.is_empty() && self.tablesample.is_none()
+            && self.whre.is_empty() && self.scope.is_empty() && self.conds.is_empty()
+            && self.group_by.is_none() && self.having.is_empty() && self.having_raw.is_empty();
+
+        match (self.select.as_slice(), self.from.as_slice()) {
+            ([SelectItem::Column("*")], [FromItem::Table(t)]) if plain => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Sets the limit value of the Query to the value of `l`
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let mut q = SelectQuery::select(&["user"]).from("users");
+    /// q.limit(12);
+    ///
+    /// assert_eq!(q.as_string(), "SELECT user FROM users LIMIT 12")
+    /// ```
+    pub fn limit(&mut self, l: usize) {
+        self.limit = Some(l);
+    }
+
+    /// Return whether or not the [`SelectQuery`] has a limit
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let mut q = SelectQuery::select(&["user"]).from("users");
+    /// q.limit(12);
+    /// 
+    /// assert!(q.has_limit());
+    /// 
+    /// q.clear_limit();
+    /// assert!(!q.has_limit());
+    /// ```
+    /// [`SelectQuery`]: ./struct.SelectQuery.html
+    pub fn has_limit(&self) -> bool {
+        self.limit.is_some()
+    }
+
+    /// Returns the value of the Limit of the [`SelectQuery`] if there is one
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::SelectQuery;
+    /// 
+    /// let mut q = SelectQuery::select(&["user"]).from("users");
+    /// assert_eq!(q.get_limit(), None);
+    /// 
+    /// q.limit(12);
+    /// assert_eq!(q.get_limit(), Some(12));
+    /// ```
+    /// [`SelectQuery`]: ./struct.SelectQuery.html
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Removes the limit from the query
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let mut q = SelectQuery::select(&["user"]).from("users");
+    /// 
+    /// // set the limit
+    /// q.limit(42);
+    /// assert_eq!(q.as_string(), "SELECT user FROM users LIMIT 42");
+    ///
+    /// // clear limit
+    /// q.clear_limit();
+    /// 
+    /// assert_eq!(q.as_string(), "SELECT user FROM users");
+    /// ```
+    pub fn clear_limit(&mut self) {
+        self.limit = None;
+    }
+
+    /// Returns true if the query is known to return at most one row
+    ///
+    /// This is a best-effort guess and never looks at the data itself. A query counts as
+    /// single-row if it has a LIMIT of at most 1, only selects aggregates without grouping
+    /// them or, when it has no joins, AND-combines a condition `id = value`. A `false`
+    /// does not mean the query returns several rows.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, WhereClause, Value};
+    ///
+    /// let mut q = SelectQuery::select(&["name"]).from("users");
+    /// assert!(!q.is_single_row());
+    ///
+    /// q.whre.push(WhereClause::new("id", Value::Int(4)));
+    /// assert!(q.is_single_row());
+    /// ```
+    pub fn is_single_row(&self) -> bool {
+        if matches!(self.limit, Some(l) if l <= 1) {
+            return true;
+        }
+        /* a raw head may select anything */
+        if self.raw.is_some() {
+            return false;
+        }
+
+        let aggregated = self.select.iter().all(|s| matches!(*s, SelectItem::Aggregate(_)));
+        if !self.select.is_empty() && aggregated && self.group_by.is_none() {
+            return true;
+        }
+
+        /* every row of a join can match several rows of the other table */
+        if !self.joins.is_empty() || self.whre.iter().skip(1).any(|e| e.0 == Condition::Or) {
+            return false;
+        }
+        self.scope.iter().chain(self.whre.clauses()).any(|c| {
+            let key = c.tbl == "id" || c.tbl.ends_with(".id");
+            let single = !matches!(c.cond, Value::Null | Value::List(_) | Value::Array(_) | Value::Subquery(_));
+            key && single && c.op == Operator::Eq && c.quantifier.is_none() && !c.bare
+        })
+    }
+
+    /// Skips the first `o` rows of the result
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let mut q = SelectQuery::select(&["user"]).from("users");
+    /// q.limit(10);
+    /// q.offset(20);
+    ///
+    /// assert_eq!(q.as_string(), "SELECT user FROM users LIMIT 10 OFFSET 20")
+    /// ```
+    pub fn offset(&mut self, o: usize) {
+        self.offset = Some(o);
+    }
+
+    /// Returns the offset of the [`SelectQuery`] if there is one
+    ///
+    /// [`SelectQuery`]: ./struct.SelectQuery.html
+    pub fn get_offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Makes [`as_parameterized`] replace the limit and offset with placeholders too
+    ///
+    /// This allows one prepared statement to be used for different page sizes. The
+    /// values are bound in the position of their placeholders, i.e. after all values
+    /// of the WHERE clause.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause};
+    ///
+    /// let mut q = SelectQuery::select(&["name"]).from("users");
+    /// q.whre.push(WhereClause::new("active", Value::Bool(true)));
+    /// q.limit(10);
+    /// q.offset(20);
+    /// q.bind_limit();
+    ///
+    /// let (sql, values) = q.as_parameterized();
+    /// assert_eq!(sql, "SELECT name FROM users WHERE active = ? LIMIT ? OFFSET ?");
+    /// assert_eq!(values, vec![Value::Bool(true), Value::UnsignedBigint(10), Value::UnsignedBigint(20)]);
+    ///
+    /// assert_eq!(q.as_string(), "SELECT name FROM users WHERE active = TRUE LIMIT 10 OFFSET 20");
+    /// ```
+    ///
+    /// [`as_parameterized`]: #method.as_parameterized
+    pub fn bind_limit(&mut self) {
+        self.bind_limit = true;
+    }
+
+    /// Selects page `page` of the result, with `per_page` rows on every page
+    ///
+    /// Pages are numbered starting at 1, so page 1 starts at offset 0, page 2 skips
+    /// the first `per_page` rows and so on. A `page` of 0, or a page whose offset
+    /// does not fit into a `usize`, returns an error and leaves the query unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, QueryError};
+    ///
+    /// let mut q = SelectQuery::select(&["user"]).from("users");
+    /// q.paginate(3, 25).unwrap();
+    ///
+    /// assert_eq!(q.as_string(), "SELECT user FROM users LIMIT 25 OFFSET 50");
+    /// assert_eq!(q.paginate(0, 25), Err(QueryError::PageZero));
+    /// ```
+    pub fn paginate(&mut self, page: usize, per_page: usize) -> Result<(), QueryError> {
+        if page == 0 {
+            return Err(QueryError::PageZero);
+        }
+
+        let offset = (page - 1).checked_mul(per_page)
+            .ok_or(QueryError::PageOutOfRange { page, per_page })?;
+
+        self.limit = Some(per_page);
+        self.offset = Some(offset);
+        Ok(())
+    }
+
+    /// Groups the rows of the result as described by `g`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, GroupBy, Aggregate};
+    ///
+    /// let mut q = SelectQuery::select(&["country"]).from("users");
+    /// q.select_aggregate(Aggregate::count("*"));
+    /// q.group_by(GroupBy::Columns(&["country"]));
+    ///
+    /// assert_eq!(q.as_string(), "SELECT country, COUNT(*) FROM users GROUP BY country");
+    /// ```
+    pub fn group_by(&mut self, g: GroupBy<'a>) {
+        self.group_by = Some(g);
+    }
+
+    /// Adds the condition `clause` on the groups of the query
+    ///
+    /// The conditions are rendered as a HAVING clause, in the same way as the
+    /// conditions of a WHERE clause. The column of `clause` is usually an aggregate.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, GroupBy, WhereClause, Operator, Value};
+    ///
+    /// let mut q = SelectQuery::select(&["country"]).from("users");
+    /// q.group_by(GroupBy::Columns(&["country"]));
+    /// q.having(WhereClause::with_operator("COUNT(*)", Operator::Gt, Value::Int(100)));
+    ///
+    /// assert_eq!(q.as_string(), "SELECT country FROM users GROUP BY country HAVING COUNT(*) > 100");
+    /// ```
+    pub fn having(&mut self, clause: WhereClause<'a>) {
+        self.having.push(clause);
+    }
+
+    /// Adds the condition `clause` on the groups of the query, combined with the
+    /// conditions before it using `OR`
+    ///
+    /// See [`having`] for details.
+    ///
+    /// [`having`]: #method.having
+    pub fn or_having(&mut self, clause: WhereClause<'a>) {
+        self.having.push_or(clause);
+    }
+
+    /// Adds the condition `condition` on the groups of the query, written as it is
+    ///
+    /// This is an escape hatch for conditions [`having`] can not express. The condition
+    /// is not escaped in any way, so it must never contain user input, as that would allow
+    /// SQL injection. Raw conditions are AND-combined with all others and parenthesized
+    /// unless there is only one.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, GroupBy};
+    ///
+    /// let mut q = SelectQuery::select(&["team"]).from("players");
+    /// q.group_by(GroupBy::Columns(&["team"]));
+    /// q.having_raw("MAX(score) - MIN(score) > 10");
+    ///
+    /// assert_eq!(q.as_string(), "SELECT team FROM players GROUP BY team HAVING MAX(score) - MIN(score) > 10");
+    /// ```
+    ///
+    /// [`having`]: #method.having
+    pub fn having_raw(&mut self, condition: &'a str) {
+        self.having_raw.push(condition);
+    }
+
+    /// Adds a ORDER BY clause to the query
+    pub fn order_by(&mut self, ob: OrderBy<'a>) {
+        self.order_by = Some(ob);
+    }
+    /// Restricts the query to rows where `column` equals `value`
+    ///
+    /// Unlike the clauses in `whre`, a scope can not be removed from the query again
+    /// and is always AND-combined with all other conditions. This makes it suitable
+    /// for mandatory filters such as tenant isolation.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause, Condition};
+    ///
+    /// let mut query = SelectQuery::select(&["*"]).from("users");
+    /// query.scope("tenant_id", Value::Int(7));
+    /// query.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    /// query.whre.push_or(WhereClause::new("name", Value::Varchar("bert")));
+    ///
+    /// assert_eq!(query.as_string(), "SELECT * FROM users WHERE tenant_id = 7 AND (name = 'anna' OR name = 'bert')");
+    /// ```
+    pub fn scope(&mut self, column: &'a str, value: Value<'a>) {
+        self.scope.push(WhereClause::new(column, value));
+    }
+
+    /// Adds the condition tree `cond` to the WHERE-clause of the query
+    ///
+    /// The condition is AND-combined with the clauses in `whre` and put in parentheses
+    /// if it contains an `OR`. See [`Cond`] for an example.
+    ///
+    /// [`Cond`]: ./struct.Cond.html
+    pub fn where_cond(&mut self, cond: Cond<'a>) {
+        self.conds.push(cond);
+    }
+
+    /// Adds `clause` to `whre`, combined with the clauses before it using `AND`
+    ///
+    /// A clause does not carry a [`Condition`] of its own, so the same clause can be
+    /// attached to one query with `AND` and to another one with [`or_where`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, WhereClause, Value};
+    ///
+    /// let vip = WhereClause::new("vip", Value::Bool(true));
+    ///
+    /// let mut q = SelectQuery::select(&["*"]).from("users");
+    /// q.and_where(WhereClause::new("active", Value::Bool(true)));
+    /// q.and_where(vip.clone());
+    /// assert_eq!(q.as_string(), "SELECT * FROM users WHERE active = TRUE AND vip = TRUE");
+    ///
+    /// let mut q = SelectQuery::select(&["*"]).from("users");
+    /// q.and_where(WhereClause::new("admin", Value::Bool(true)));
+    /// q.or_where(vip);
+    /// assert_eq!(q.as_string(), "SELECT * FROM users WHERE admin = TRUE OR vip = TRUE");
+    /// ```
+    ///
+    /// [`Condition`]: ./enum.Condition.html
+    /// [`or_where`]: #method.or_where
+    pub fn and_where(&mut self, clause: WhereClause<'a>) {
+        self.whre.push(clause);
+    }
+
+    /// Adds `clause` to `whre`, combined with the clauses before it using `OR`
+    ///
+    /// See [`and_where`] for an example.
+    ///
+    /// [`and_where`]: #method.and_where
+    pub fn or_where(&mut self, clause: WhereClause<'a>) {
+        self.whre.push_or(clause);
+    }
+
+    /// Excludes soft-deleted rows, i.e. rows where `column` is not `NULL`
+    ///
+    /// Like a [`scope`] the filter can not be removed again and is AND-combined
+    /// with all other conditions.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause};
+    ///
+    /// let mut query = SelectQuery::select(&["*"]).from("users");
+    /// query.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    /// query.exclude_deleted("deleted_at");
+    ///
+    /// assert_eq!(query.as_string(), "SELECT * FROM users WHERE deleted_at IS NULL AND name = 'anna'");
+    /// ```
+    ///
+    /// [`scope`]: #method.scope
+    pub fn exclude_deleted(&mut self, column: &'a str) {
+        self.scope.push(WhereClause::with_operator(column, Operator::Is, Value::Null));
+    }
+
+    /// Restricts the query to the rows whose `column` is one of `ids`
+    ///
+    /// This adds `column IN (...)` to the [`WhereClause`]s. As an empty `IN` list is
+    /// not valid SQL, an empty `ids` returns an error and leaves the query unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, QueryError};
+    ///
+    /// let mut q = SelectQuery::select(&["*"]).from("users");
+    /// q.by_ids("id", &[Value::Int(1), Value::Int(4)]).unwrap();
+    ///
+    /// assert_eq!(q.as_string(), "SELECT * FROM users WHERE id IN (1, 4)");
+    /// assert_eq!(q.by_ids("id", &[]), Err(QueryError::EmptyList));
+    /// ```
+    ///
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    pub fn by_ids(&mut self, column: &'a str, ids: &[Value<'a>]) -> Result<(), QueryError> {
+        if ids.is_empty() {
+            return Err(QueryError::EmptyList);
+        }
+
+        self.whre.push(WhereClause::with_operator(column, Operator::In, Value::List(ids.to_vec())));
+        Ok(())
+    }
+
+    /// Adds a [`WhereClause`] for every entry of `filters`, requiring the column to equal the value
+    ///
+    /// The clauses are AND-combined and added in the order of the map, just like the
+    /// `set` and `values` maps of the other queries.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use query_builder::{SelectQuery, Value};
+    ///
+    /// let mut filters = BTreeMap::new();
+    /// filters.insert("name", Value::Varchar("anna"));
+    /// filters.insert("city", Value::Varchar("Oslo"));
+    ///
+    /// let mut query = SelectQuery::select(&["*"]).from("users");
+    /// query.where_eq_all(filters);
+    ///
+    /// assert_eq!(query.as_string(), "SELECT * FROM users WHERE city = 'Oslo' AND name = 'anna'");
+    /// ```
+    ///
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    pub fn where_eq_all(&mut self, filters: BTreeMap<&'a str, Value<'a>>) {
+        for (column, value) in filters {
+            self.whre.push(WhereClause::new(column, value));
+        }
+    }
+
+    /// Adds the [`WhereClause`]s of the [`QueryFilter`] `filter` to the query
+    ///
+    /// See [`QueryFilter`] for an example.
+    ///
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    /// [`QueryFilter`]: ./trait.QueryFilter.html
+    pub fn filter(&mut self, filter: &dyn QueryFilter<'a>) {
+        self.whre.extend(filter.to_where());
+    }
+
+    /// Adds a [`WhereClause`] comparing `column` with `value` using `op`, but only if there is a value
+    ///
+    /// This is meant for optional filter inputs, a `None` leaves the query unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, Operator};
+    ///
+    /// let min_age: Option<i32> = Some(18);
+    /// let city: Option<&str> = None;
+    ///
+    /// let mut query = SelectQuery::select(&["*"]).from("users");
+    /// query.where_opt("age", Operator::GtEq, min_age.map(Value::Int), None);
+    /// query.where_opt("city", Operator::Eq, city.map(Value::Varchar), None);
+    ///
+    /// assert_eq!(query.as_string(), "SELECT * FROM users WHERE age >= 18");
+    /// ```
+    ///
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    pub fn where_opt(&mut self, column: &'a str, op: Operator, value: Option<Value<'a>>, how: Option<Condition>) {
+        if let Some(v) = value {
+            self.whre.push_with(how.unwrap_or(Condition::And), WhereClause::with_operator(column, op, v));
+        }
+    }
+
+    /// Sorts the [`WhereClause`]s of the query into a deterministic order
+    ///
+    /// Queries with the same conditions render to the same SQL after this, no matter
+    /// in which order the conditions were added. The clauses are only reordered if they
+    /// are all combined with the same [`Condition`], otherwise reordering could change
+    /// which rows match and the query is left as it is.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause};
+    ///
+    /// let mut a = SelectQuery::select(&["*"]).from("users");
+    /// a.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    /// a.whre.push(WhereClause::new("age", Value::Int(30)));
+    ///
+    /// let mut b = SelectQuery::select(&["*"]).from("users");
+    /// b.whre.push(WhereClause::new("age", Value::Int(30)));
+    /// b.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    ///
+    /// a.canonicalize();
+    /// b.canonicalize();
+    /// assert_eq!(a.as_string(), b.as_string());
+    /// ```
+    ///
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    /// [`Condition`]: ./enum.Condition.html
+    pub fn canonicalize(&mut self) {
+        canonicalize(&mut self.whre);
+    }
+
+    /// Renders the query against the table `new` instead of `old`
+    ///
+    /// This replaces `old` wherever it is used as a table, as well as in every column
+    /// qualified with it (`old.column`), including column references in values and
+    /// subqueries. Raw SQL fragments like [`JoinCondition::On`] are left untouched.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause};
+    ///
+    /// let mut q = SelectQuery::select(&["users.name"]).from("users");
+    /// q.whre.push(WhereClause::new("users.id", Value::Int(3)));
+    /// q.rename_table("users", "tmp_users");
+    ///
+    /// assert_eq!(q.as_string(), "SELECT tmp_users.name FROM tmp_users WHERE tmp_users.id = 3");
+    /// ```
+    ///
+    /// [`JoinCondition::On`]: ./enum.JoinCondition.html#variant.On
+    pub fn rename_table(&mut self, old: &'a str, new: &'a str) {
+        self.renames.push((old, new));
+    }
+
+    /// Creates the string representation of the query
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let mut q = SelectQuery::select(&["*"]).from("users");
+    ///
+    /// assert_eq!(q.as_string(), "SELECT * FROM users")
+    /// ```
+    pub fn as_string(&self) -> String {
+        let mut res = String::new();
+        /* writing into a String never fails */
+        self.write_to(&mut res).unwrap();
+        res
+    }
+
+    /// Writes the SQL of [`as_string`] into `w` instead of returning a new [`String`]
+    ///
+    /// This allows reusing one buffer for rendering many queries.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let query = SelectQuery::select(&["name"]).from("users");
+    ///
+    /// let mut buf = String::new();
+    /// query.write_to(&mut buf).unwrap();
+    /// assert_eq!(buf, query.as_string());
+    /// ```
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn write_to<W: Write>(&self, w: &mut W) -> FormatResult {
+        w.write_str(&self.render(&mut Context::literal(self.dialect).rendered_by(self.renderer)))
+    }
+
+    /// Returns the query with a placeholder for every value, along with
+    /// the values in the order of their placeholders
+    ///
+    /// Placeholders are numbered (`$1`, `$2`, ...) for [`Dialect::Postgres`] and
+    /// `?` for all other dialects. Column references and intervals are part of
+    /// the statement and are never replaced.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause};
+    ///
+    /// let mut query = SelectQuery::select(&["name"]).from("users");
+    /// query.whre.push(WhereClause::new("age", Value::Int(30)));
+    ///
+    /// let (sql, values) = query.as_parameterized();
+    /// assert_eq!(sql, "SELECT name FROM users WHERE age = ?");
+    /// assert_eq!(values, vec![Value::Int(30)]);
+    /// ```
+    ///
+    /// [`Dialect::Postgres`]: ./enum.Dialect.html#variant.Postgres
+    pub fn as_parameterized(&self) -> (String, Vec<Value<'a>>) {
+        self.as_parameterized_from(1)
+    }
+
+    /// Works like [`as_parameterized`], but numbers the placeholders from `start_index` on
+    ///
+    /// This allows the SQL to be spliced into a larger statement that already has
+    /// `start_index - 1` parameters. Only the numbered placeholders of [`Dialect::Postgres`]
+    /// are affected.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause, Dialect};
+    ///
+    /// let mut query = SelectQuery::select(&["name"]).from("users");
+    /// query.whre.push(WhereClause::new("age", Value::Int(30)));
+    /// query.dialect(Dialect::Postgres);
+    ///
+    /// let (sql, values) = query.as_parameterized_from(3);
+    /// assert_eq!(sql, "SELECT name FROM users WHERE age = $3");
+    /// assert_eq!(values, vec![Value::Int(30)]);
+    /// ```
+    ///
+    /// [`as_parameterized`]: #method.as_parameterized
+    /// [`Dialect::Postgres`]: ./enum.Dialect.html#variant.Postgres
+    pub fn as_parameterized_from(&self, start_index: usize) -> (String, Vec<Value<'a>>) {
+        let mut ctx = Context::placeholders(self.dialect).starting_at(start_index);
+        let sql = self.render(&mut ctx);
+
+        (sql, ctx.into_values())
+    }
+
+    /// Returns the query with all values written out, for logging only
+    ///
+    /// Unlike [`as_string`], quotes inside text values are escaped, as are backslashes on
+    /// MySQL, so the log shows the statement the database would run with the bound values
+    /// of [`as_parameterized`].
+    /// The result is still not meant to be executed, always bind untrusted values.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause};
+    ///
+    /// let mut query = SelectQuery::select(&["*"]).from("users");
+    /// query.whre.push(WhereClause::new("name", Value::Varchar("O'Brien")));
+    ///
+    /// assert_eq!(query.as_debug_string(), "SELECT * FROM users WHERE name = 'O''Brien'");
+    /// ```
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`as_parameterized`]: #method.as_parameterized
+    pub fn as_debug_string(&self) -> String {
+        self.render(&mut Context::escaped(self.dialect).rendered_by(self.renderer))
+    }
+
+    /// Returns the query like [`as_string`] along with [`Warning`]s about likely mistakes
+    ///
+    /// This is meant for a dry run before deploying a query, the warnings never
+    /// stop the query from being rendered.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Warning};
+    ///
+    /// let mut query = SelectQuery::select(&["*"]).from("users");
+    /// query.limit(10);
+    ///
+    /// let (sql, warnings) = query.render_with_warnings();
+    /// assert_eq!(sql, "SELECT * FROM users LIMIT 10");
+    /// assert_eq!(warnings, vec![Warning::UnorderedLimit, Warning::SelectStar]);
+    /// ```
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`Warning`]: ./enum.Warning.html
+    pub fn render_with_warnings(&self) -> (String, Vec<Warning>) {
+        let mut warnings = Vec::new();
+        if self.limit.is_some() && self.order_by.is_none() {
+            warnings.push(Warning::UnorderedLimit);
+        }
+        if self.selects_star() {
+            warnings.push(Warning::SelectStar);
+        }
+        (self.as_string(), warnings)
+    }
+
+    /// Returns the query with a placeholder for every value, along with the values
+    ///
+    /// The clauses are put into canonical order first, see [`canonicalize`], so queries
+    /// only differing in their values or the order of their conditions get the same SQL.
+    /// This makes the SQL suitable as the key of a prepared statement cache.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause};
+    ///
+    /// let mut first = SelectQuery::select(&["*"]).from("users");
+    /// first.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    /// first.whre.push(WhereClause::new("age", Value::Int(30)));
+    ///
+    /// let mut second = SelectQuery::select(&["*"]).from("users");
+    /// second.whre.push(WhereClause::new("age", Value::Int(41)));
+    /// second.whre.push(WhereClause::new("name", Value::Varchar("bert")));
+    ///
+    /// let (key, values) = first.into_prepared();
+    /// assert_eq!(key, "SELECT * FROM users WHERE age = ? AND name = ?");
+    /// assert_eq!(values, vec![Value::Int(30), Value::Varchar("anna")]);
+    /// assert_eq!(second.into_prepared().0, key);
+    /// ```
+    ///
+    /// [`canonicalize`]: #method.canonicalize
+    pub fn into_prepared(mut self) -> (String, Vec<Value<'a>>) {
+        self.canonicalize();
+        self.as_parameterized()
+    }
+
+    /// Returns only the WHERE clause of the query with placeholders, along with
+    /// the values in the order of their placeholders
+    ///
+    /// This is meant for code that builds the rest of the statement itself. The
+    /// clause includes the [`scope`] conditions, and is empty if there are no conditions.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause};
+    ///
+    /// let mut query = SelectQuery::select(&["name"]).from("users");
+    /// query.whre.push(WhereClause::new("age", Value::Int(30)));
+    /// query.whre.push(WhereClause::new("city", Value::Varchar("berlin")));
+    ///
+    /// let (sql, values) = query.where_parameterized();
+    /// assert_eq!(sql, "WHERE age = ? AND city = ?");
+    /// assert_eq!(values, vec![Value::Int(30), Value::Varchar("berlin")]);
+    /// ```
+    ///
+    /// [`scope`]: #method.scope
+    pub fn where_parameterized(&self) -> (String, Vec<Value<'a>>) {
+        let mut ctx = Context::placeholders(self.dialect);
+        let sql = Clauses {
+            scope: &self.scope,
+            whre: &self.whre.entries,
+            conds: &self.conds,
+            group_by: None,
+            having: &[],
+            having_raw: &[],
+            order_by: None,
+            limit: None,
+            offset: None,
+            bind_limit: false,
+            max_conditions: self.max_conditions,
+        }.where_clause(&mut ctx).unwrap_or_default();
+
+        (sql, ctx.into_values())
+    }
+
+    /// Returns the values that [`as_parameterized`] binds, each paired with the
+    /// column it is compared with or assigned to
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause};
+    ///
+    /// let mut query = SelectQuery::select(&["name"]).from("users");
+    /// query.whre.push(WhereClause::new("age", Value::Int(30)));
+    ///
+    /// assert_eq!(query.parameter_manifest(), vec![("age".to_string(), Value::Int(30))]);
+    /// ```
+    ///
+    /// [`as_parameterized`]: #method.as_parameterized
+    pub fn parameter_manifest(&self) -> Vec<(String, Value<'a>)> {
+        let mut ctx = Context::placeholders(self.dialect);
+        self.render(&mut ctx);
+
+        ctx.bound
+    }
+
+    /// Returns every value written into the query, in the order they appear in it
+    ///
+    /// This includes the values of subqueries and does not depend on whether the
+    /// query is parameterized, which makes it useful for auditing. Lists are replaced
+    /// by their items, column references are left out as they are not values.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause, Operator};
+    ///
+    /// let mut inner = SelectQuery::select(&["user_id"]).from("orders");
+    /// inner.whre.push(WhereClause::new("total", Value::Int(100)));
+    ///
+    /// let mut query = SelectQuery::select(&["name"]).from("users");
+    /// query.whre.push(WhereClause::new("active", Value::Bool(true)));
+    /// query.whre.push(WhereClause::with_operator("id", Operator::In, Value::Subquery(Box::new(inner))));
+    ///
+    /// assert_eq!(query.collect_values(), vec![&Value::Bool(true), &Value::Int(100)]);
+    /// ```
+    pub fn collect_values(&self) -> Vec<&Value<'a>> {
+        let mut values = Vec::new();
+        for f in &self.from {
+            match *f {
+                FromItem::Subquery(ref q) => values.extend(q.collect_values()),
+                FromItem::Values(ref v) => values.extend(v.collect_values()),
+                FromItem::Table(_) | FromItem::Function(..) => {},
+            }
+        }
+        for j in &self.joins {
+            if let Some(ref q) = j.subquery {
+                values.extend(q.collect_values());
+            }
+        }
+        collect_clauses(&self.scope, &mut values);
+        collect_clauses(self.whre.clauses(), &mut values);
+        for c in &self.conds {
+            collect_conds(c, &mut values);
+        }
+        collect_clauses(self.having.clauses(), &mut values);
+        values
+    }
+
+    /// Combines all `queries` with `UNION ALL`
+    ///
+    /// A single query is rendered as it is, without any `UNION`. Returns an error
+    /// if there are no queries at all.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, QueryError};
+    ///
+    /// let queries = vec![
+    ///     SelectQuery::select(&["name"]).from("users"),
+    ///     SelectQuery::select(&["name"]).from("admins"),
+    /// ];
+    ///
+    /// let union = SelectQuery::union_all_many(queries).unwrap();
+    /// assert_eq!(union.as_string(), "SELECT name FROM users UNION ALL SELECT name FROM admins");
+    ///
+    /// assert_eq!(SelectQuery::union_all_many(Vec::new()).unwrap_err(), QueryError::EmptyUnion);
+    /// ```
+    pub fn union_all_many(queries: Vec<SelectQuery<'a>>) -> Result<UnionQuery<'a>, QueryError> {
+        if queries.is_empty() {
+            return Err(QueryError::EmptyUnion);
+        }
+
+        Ok(UnionQuery { queries, op: SetOperation::UnionAll })
+    }
+
+    /// Returns the rows of the query that `other` does not return, rendered as `EXCEPT`
+    ///
+    /// Duplicate rows are removed, MySQL does not support this.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let users = SelectQuery::select(&["email"]).from("users");
+    /// let banned = SelectQuery::select(&["email"]).from("banned");
+    ///
+    /// assert_eq!(users.except(banned).as_string(), "SELECT email FROM users EXCEPT SELECT email FROM banned");
+    /// ```
+    pub fn except(self, other: SelectQuery<'a>) -> UnionQuery<'a> {
+        self.combine(other, SetOperation::Except)
+    }
+
+    /// Like [`except`], but keeps duplicate rows, rendered as `EXCEPT ALL`
+    ///
+    /// Only supported by Postgres.
+    ///
+    /// [`except`]: #method.except
+    pub fn except_all(self, other: SelectQuery<'a>) -> UnionQuery<'a> {
+        self.combine(other, SetOperation::ExceptAll)
+    }
+
+    /// Returns the rows that both the query and `other` return, rendered as `INTERSECT`
+    ///
+    /// Duplicate rows are removed, MySQL does not support this.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::SelectQuery;
+    ///
+    /// let buyers = SelectQuery::select(&["user_id"]).from("orders");
+    /// let reviewers = SelectQuery::select(&["user_id"]).from("reviews");
+    ///
+    /// assert_eq!(
+    ///     buyers.intersect(reviewers).as_string(),
+    ///     "SELECT user_id FROM orders INTERSECT SELECT user_id FROM reviews"
+    /// );
+    /// ```
+    pub fn intersect(self, other: SelectQuery<'a>) -> UnionQuery<'a> {
+        self.combine(other, SetOperation::Intersect)
+    }
+
+    /// Like [`intersect`], but keeps duplicate rows, rendered as `INTERSECT ALL`
+    ///
+    /// Only supported by Postgres.
+    ///
+    /// [`intersect`]: #method.intersect
+    pub fn intersect_all(self, other: SelectQuery<'a>) -> UnionQuery<'a> {
+        self.combine(other, SetOperation::IntersectAll)
+    }
+
+    /// Combines the query with `other` using the set operation `op`
+    fn combine(self, other: SelectQuery<'a>, op: SetOperation) -> UnionQuery<'a> {
+        UnionQuery { queries: vec![self, other], op }
+    }
+
+    /// Sets the [`Dialect`] the query is rendered for
+    ///
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn dialect(&mut self, dialect: Dialect) {
+        self.dialect = dialect;
+    }
+
+    /// Sets the [`ValueRenderer`] used for the values written into the query,
+    /// instead of the one of its [`Dialect`]
+    ///
+    /// [`ValueRenderer`]: ./trait.ValueRenderer.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn renderer(&mut self, renderer: &'a dyn ValueRenderer) {
+        self.renderer = Some(renderer);
+    }
+
+    /// Sets whether the keywords of the query are written in upper or lower case
+    ///
+    /// [`KeywordCase::Upper`] is used unless told otherwise.
+    ///
+    /// [`KeywordCase::Upper`]: ./enum.KeywordCase.html#variant.Upper
+    pub fn keyword_case(&mut self, case: KeywordCase) {
+        self.keyword_case = case;
+    }
+
+    /// Limits the query to at most `max` conditions, as a guard against accidentally huge queries
+    ///
+    /// Every [`WhereClause`] counts as one condition, except for `IN` lists, which count
+    /// every value. [`try_as_string`] and [`validate`] fail if there are more.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause, Operator, QueryError};
+    ///
+    /// let mut query = SelectQuery::select(&["*"]).from("users");
+    /// query.max_conditions(2);
+    /// query.whre.push(WhereClause::with_operator("id", Operator::In, Value::List(vec![Value::Int(1), Value::Int(2), Value::Int(3)])));
+    ///
+    /// assert_eq!(query.validate(), Err(QueryError::TooManyConditions { count: 3, max: 2 }));
+    /// ```
+    ///
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    /// [`try_as_string`]: #method.try_as_string
+    /// [`validate`]: #method.validate
+    pub fn max_conditions(&mut self, max: usize) {
+        self.max_conditions = Some(max);
+    }
+
+    /// Restricts the columns the query may select and order by to `allowed`
+    ///
+    /// This guards queries that take column names from user input, e.g. the sort column
+    /// of a search page. [`try_as_string`] and [`validate`] fail with
+    /// [`QueryError::UnexpectedColumn`] for every other column, a raw ordering expression
+    /// has to be allowed as a whole. Expressions added with [`select_raw`] are not checked.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, OrderBy, QueryError};
+    ///
+    /// let mut query = SelectQuery::select(&["name", "email"]).from("users");
+    /// query.restrict_columns(&["name", "email", "created"]);
+    ///
+    /// query.order_by(OrderBy::RowDesc("created"));
+    /// assert!(query.validate().is_ok());
+    ///
+    /// query.order_by(OrderBy::Row("password"));
+    /// assert_eq!(query.validate(), Err(QueryError::UnexpectedColumn { column: "password".to_string() }));
+    /// ```
+    ///
+    /// [`try_as_string`]: #method.try_as_string
+    /// [`validate`]: #method.validate
+    /// [`select_raw`]: #method.select_raw
+    /// [`QueryError::UnexpectedColumn`]: ./enum.QueryError.html#variant.UnexpectedColumn
+    pub fn restrict_columns(&mut self, allowed: &[&'a str]) {
+        self.allowed_columns = Some(allowed.to_vec());
+    }
+
+    /// Returns true if the query selects all columns of a table with `*`
+    fn selects_star(&self) -> bool {
+        self.select.iter().any(|s| match *s {
+            SelectItem::Column(c) => c == "*" || c.ends_with(".*"),
+            _ => false,
+        })
+    }
+
+    /// Records an error for every GROUP BY ordinal beyond the selected columns
+    fn check_ordinals(&self, ctx: &mut Context) {
+        let group_by = match self.group_by {
+            Some(ref g) => g,
+            None => return,
+        };
+        /* the number of columns is only known if every item is a single column */
+        if self.raw.is_some() || self.selects_star() {
+            return;
+        }
+
+        let columns = self.select.len();
+        for ordinal in group_by.columns().into_iter().filter_map(|c| c.parse::<usize>().ok()) {
+            if ordinal == 0 || ordinal > columns {
+                ctx.errors.push(QueryError::OrdinalOutOfRange { ordinal, columns });
+            }
+        }
+    }
+
+    /// Records an error for every selected or ordered column that is not allowed by `restrict_columns`
+    fn check_columns(&self, ctx: &mut Context) {
+        let allowed = match self.allowed_columns {
+            Some(ref a) => a,
+            None => return,
+        };
+
+        let mut selected = Vec::new();
+        for s in &self.select {
+            match *s {
+                SelectItem::Column(c) => selected.push(c),
+                /* COUNT(*) does not read any column */
+                SelectItem::Aggregate(ref a) if a.column != "*" => {
+                    selected.push(a.column);
+                    selected.extend(a.more);
+                },
+                SelectItem::Coalesce(l, r, _) => selected.extend(&[l, r]),
+                _ => {},
+            }
+        }
+        for column in selected.into_iter().chain(self.order_by.as_ref().map(|o| o.column())) {
+            if !allowed.contains(&column) {
+                ctx.errors.push(QueryError::UnexpectedColumn { column: column.to_string() });
+            }
+        }
+    }
+
+    /// Returns the [`String`] representation of the [`SelectQuery`], or an error if
+    /// the query uses features its [`Dialect`] does not support
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{SelectQuery, Value, WhereClause, Dialect};
+    ///
+    /// let mut query = SelectQuery::select(&["*"]).from("users");
+    /// query.whre.push(WhereClause::new("tags", Value::Array(vec![Value::Int(1)])));
+    /// assert!(query.try_as_string().is_err());
+    ///
+    /// query.dialect(Dialect::Postgres);
+    /// assert!(query.try_as_string().is_ok());
+    /// ```
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`SelectQuery`]: ./struct.SelectQuery.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn try_as_string(&self) -> Result<String, QueryError> {
+        let mut ctx = Context::literal(self.dialect).rendered_by(self.renderer);
+        let sql = self.render(&mut ctx);
+
+        ctx.finish(sql)
+    }
+
+    /// Checks whether the [`SelectQuery`] can be rendered for its [`Dialect`]
+    ///
+    /// [`SelectQuery`]: ./struct.SelectQuery.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn validate(&self) -> Result<(), QueryError> {
+        self.try_as_string().map(|_| ())
+    }
+
+    /// Renders the query, passing all values through `ctx`
+    fn render(&self, ctx: &mut Context<'a>) -> String {
+        ctx.choose_case(self.keyword_case);
+        let renamed = ctx.push_renames(&self.renames);
+        let mut res = match self.raw {
+            Some(raw) => raw.to_string(),
+            None => self.render_head(ctx),
+        };
+        self.check_columns(ctx);
+        self.check_ordinals(ctx);
+
+        Clauses {
+            scope: &self.scope,
+            whre: &self.whre.entries,
+            conds: &self.conds,
+            group_by: self.group_by.as_ref(),
+            having: &self.having.entries,
+            having_raw: &self.having_raw,
+            order_by: self.order_by.as_ref(),
+            limit: self.limit,
+            offset: self.offset,
+            bind_limit: self.bind_limit,
+            max_conditions: self.max_conditions,
+        }.append_to(&mut res, ctx);
+
+        ctx.renames.truncate(renamed);
+        res
+    }
+
+    /// Renders everything up to the WHERE clause
+    fn render_head(&self, ctx: &mut Context<'a>) -> String {
+        if let Some(table) = self.shorthand_table(ctx.dialect) {
+            return ctx.case().format("TABLE {}{}", &[&only(self.only, ctx), &ctx.ident(table)]);
+        }
+
+        let mut res = ctx.kw("SELECT ");
+        for h in &self.hints {
+            if let Hint::Optimizer(text) = *h {
+                /* a closing marker in the hint would end it early */
+                res.push_str(&format!("/*+ {} */ ", text.replace("*/", "* /")));
+            }
+        }
+
+        let select: Vec<String> = self.select.iter().map(|s| s.render(ctx)).collect();
+        if self.distinct {
+            res.push_str(&ctx.kw("DISTINCT "));
+            if let Some(ref ob) = self.order_by {
+                let (key, _) = ob.key(ctx);
+                let alias = ctx.kw(" AS ");
+                /* the key may also be the alias of a selected column */
+                let selected = select.iter().any(|s| s == "*" || *s == key || s.rsplit(alias.as_str()).next() == Some(&key));
+                if !selected {
+                    ctx.errors.push(QueryError::OrderNotSelected { column: key });
+                }
+            }
+        }
+        res.push_str(&select.join(", "));
+
+        if !self.from.is_empty() {
+            let only = only(self.only, ctx);
+            let mut from: Vec<String> = self.from.iter().map(|f| match *f {
+                FromItem::Table(t) => format!("{}{}", only, ctx.ident(t)),
+                _ => f.render(ctx),
+            }).collect();
+            from[0].push_str(&partition(&self.partitions, ctx));
+
+            if let Some((method, percent)) = self.tablesample {
+                match method {
+                    SampleMethod::System => ctx.require("TABLESAMPLE clauses", &[Dialect::Postgres, Dialect::SqlServer]),
+                    SampleMethod::Bernoulli => ctx.require("BERNOULLI samples", &[Dialect::Postgres]),
+                }
+                from[0] = match ctx.dialect {
+                    Dialect::SqlServer => ctx.case().format("{} TABLESAMPLE {} ({} PERCENT)", &[&from[0], &ctx.kw(method), &percent]),
+                    _ => ctx.case().format("{} TABLESAMPLE {} ({})", &[&from[0], &ctx.kw(method), &percent]),
+                };
+            }
+
+            for h in &self.hints {
+                if let Some(index) = h.render_index(ctx) {
+                    from[0] = format!("{} {}", from[0], index);
+                }
+            }
+
+            res.push_str(&ctx.kw(" FROM "));
+            res.push_str(&from.join(", "));
+        }
+
+        for j in &self.joins {
+            res.push(' ');
+            res.push_str(&j.render(ctx));
+        }
+
+        res
+    }
+}
+
+
+#[derive(Debug)]
+/// Struct representing an SQL Insert Statement
+pub struct InsertQuery<'a> {
+    into: &'a str,
+    pub values: BTreeMap<&'a str, Value<'a>>,
+    rows: Vec<BTreeMap<&'a str, Value<'a>>>,
+    row_layout: RowLayout,
+    max_lengths: BTreeMap<&'a str, usize>,
+    nullability: BTreeMap<&'a str, Nullability>,
+    keyword_case: KeywordCase,
+    renderer: Option<&'a dyn ValueRenderer>,
+    dialect: Dialect,
+}
+
+impl<'a> Display for InsertQuery<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+impl<'a> InsertQuery<'a> {
+    /// Creates a new [`InsertQuery`] that puts data into `table`.
+    /// 
+    /// [`InsertQuery`]: ./struct.InsertQuery.html
+    pub fn into(table: &'a str) -> InsertQuery<'a> {
+        InsertQuery {
+            into: table,
+            values: BTreeMap::new(),
+            rows: Vec::new(),
+            row_layout: RowLayout::Compact,
+            max_lengths: BTreeMap::new(),
+            nullability: BTreeMap::new(),
+            keyword_case: KeywordCase::Upper,
+            renderer: None,
+            dialect: Dialect::Generic,
+        }
+    }
+
+    /// Returns a [`String`] that represents the [`InsertQuery`] in a valid SQL statement
+    /// ## Example
+    /// ```
+    /// use query_builder::{Value, InsertQuery};
+    ///
+    /// let mut q = InsertQuery::into("users");
+    /// q.values.insert("name", Value::Varchar("greg"));
+    ///
+    /// assert_eq!(q.as_string(), "INSERT INTO users(name) VALUES('greg')")
+    /// ```
+    /// 
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`InsertQuery`]: ./struct.InsertQuery.html
+    pub fn as_string(&self) -> String {
+        let mut res = String::new();
+        /* writing into a String never fails */
+        self.write_to(&mut res).unwrap();
+        res
+    }
+
+    /// Writes the SQL of [`as_string`] into `w` instead of returning a new [`String`]
+    ///
+    /// This allows reusing one buffer for rendering many queries.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{InsertQuery, Value};
+    ///
+    /// let mut buf = String::new();
+    /// for name in ["anna", "bert"].iter() {
+    ///     let mut query = InsertQuery::into("users");
+    ///     query.values.insert("name", Value::Varchar(name));
+    ///
+    ///     buf.clear();
+    ///     query.write_to(&mut buf).unwrap();
+    ///     assert_eq!(buf, query.as_string());
+    /// }
+    /// ```
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn write_to<W: Write>(&self, w: &mut W) -> FormatResult {
+        w.write_str(&self.render(&mut Context::literal(self.dialect).rendered_by(self.renderer)))
+    }
+
+    /// Returns the query with a placeholder for every value, along with
+    /// the values in the order of their placeholders
+    ///
+    /// Placeholders are numbered (`$1`, `$2`, ...) for [`Dialect::Postgres`] and
+    /// `?` for all other dialects. Column references and intervals are part of
+    /// the statement and are never replaced.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{InsertQuery, Value, WhereClause};
+    ///
+    /// let mut query = InsertQuery::into("users");
+    /// query.values.insert("name", Value::Varchar("greg"));
+    ///
+    /// let (sql, values) = query.as_parameterized();
+    /// assert_eq!(sql, "INSERT INTO users(name) VALUES(?)");
+    /// assert_eq!(values, vec![Value::Varchar("greg")]);
+    /// ```
+    ///
+    /// [`Dialect::Postgres`]: ./enum.Dialect.html#variant.Postgres
+    pub fn as_parameterized(&self) -> (String, Vec<Value<'a>>) {
+        self.as_parameterized_from(1)
+    }
+
+    /// Works like [`as_parameterized`], but numbers the placeholders from `start_index` on
+    ///
+    /// See [`SelectQuery::as_parameterized_from`].
+    ///
+    /// [`as_parameterized`]: #method.as_parameterized
+    /// [`SelectQuery::as_parameterized_from`]: ./struct.SelectQuery.html#method.as_parameterized_from
+    pub fn as_parameterized_from(&self, start_index: usize) -> (String, Vec<Value<'a>>) {
+        let mut ctx = Context::placeholders(self.dialect).starting_at(start_index);
+        let sql = self.render(&mut ctx);
+
+        (sql, ctx.into_values())
+    }
+
+    /// Returns the query with all values written out, for logging only
+    ///
+    /// Works like [`SelectQuery::as_debug_string`].
+    ///
+    /// [`SelectQuery::as_debug_string`]: ./struct.SelectQuery.html#method.as_debug_string
+    pub fn as_debug_string(&self) -> String {
+        self.render(&mut Context::escaped(self.dialect).rendered_by(self.renderer))
+    }
+
+    /// Returns the query like [`as_string`] along with [`Warning`]s about likely mistakes
+    ///
+    /// An insert only writes the rows it is given, so none of the warnings apply and
+    /// the list is always empty. The method exists so all query types can be dry-run
+    /// the same way, see [`SelectQuery::render_with_warnings`].
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`Warning`]: ./enum.Warning.html
+    /// [`SelectQuery::render_with_warnings`]: ./struct.SelectQuery.html#method.render_with_warnings
+    pub fn render_with_warnings(&self) -> (String, Vec<Warning>) {
+        (self.as_string(), Vec::new())
+    }
+
+    /// Returns the query with a placeholder for every value, along with the values
+    ///
+    /// Works like [`SelectQuery::into_prepared`].
+    ///
+    /// [`SelectQuery::into_prepared`]: ./struct.SelectQuery.html#method.into_prepared
+    pub fn into_prepared(self) -> (String, Vec<Value<'a>>) {
+        self.as_parameterized()
+    }
+
+    /// Returns the values that [`as_parameterized`] binds, each paired with the
+    /// column it is compared with or assigned to
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{InsertQuery, Value, WhereClause};
+    ///
+    /// let mut query = InsertQuery::into("users");
+    /// query.values.insert("name", Value::Varchar("greg"));
+    ///
+    /// assert_eq!(query.parameter_manifest(), vec![("name".to_string(), Value::Varchar("greg"))]);
+    /// ```
+    ///
+    /// [`as_parameterized`]: #method.as_parameterized
+    pub fn parameter_manifest(&self) -> Vec<(String, Value<'a>)> {
+        let mut ctx = Context::placeholders(self.dialect);
+        self.render(&mut ctx);
+
+        ctx.bound
+    }
+
+    /// Returns every value written into the query, in the order they appear in it
+    ///
+    /// This includes the values of subqueries and does not depend on whether the
+    /// query is parameterized, which makes it useful for auditing. Lists are replaced
+    /// by their items, column references are left out as they are not values.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{InsertQuery, Value};
+    ///
+    /// let mut query = InsertQuery::into("users");
+    /// query.values.insert("name", Value::Varchar("greg"));
+    ///
+    /// assert_eq!(query.collect_values(), vec![&Value::Varchar("greg")]);
+    /// ```
+    pub fn collect_values(&self) -> Vec<&Value<'a>> {
+        let mut values = Vec::new();
+        for v in self.values.values().chain(self.rows.iter().flat_map(|r| r.values())) {
+            collect_value(v, &mut values);
+        }
+        values
+    }
+
+    /// Sets the [`Dialect`] the query is rendered for
+    ///
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn dialect(&mut self, dialect: Dialect) {
+        self.dialect = dialect;
+    }
+
+    /// Sets the [`ValueRenderer`] used for the values written into the query,
+    /// instead of the one of its [`Dialect`]
+    ///
+    /// [`ValueRenderer`]: ./trait.ValueRenderer.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn renderer(&mut self, renderer: &'a dyn ValueRenderer) {
+        self.renderer = Some(renderer);
+    }
+
+    /// Sets whether the keywords of the query are written in upper or lower case
+    ///
+    /// [`KeywordCase::Upper`] is used unless told otherwise.
+    ///
+    /// [`KeywordCase::Upper`]: ./enum.KeywordCase.html#variant.Upper
+    pub fn keyword_case(&mut self, case: KeywordCase) {
+        self.keyword_case = case;
+    }
+
+    /// Returns the [`String`] representation of the [`InsertQuery`], or an error if
+    /// the query uses features its [`Dialect`] does not support
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{InsertQuery, Value, Dialect};
+    ///
+    /// let mut query = InsertQuery::into("users");
+    /// query.values.insert("tags", Value::Array(vec![Value::Int(1)]));
+    /// assert!(query.try_as_string().is_err());
+    ///
+    /// query.dialect(Dialect::Postgres);
+    /// assert!(query.try_as_string().is_ok());
+    /// ```
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`InsertQuery`]: ./struct.InsertQuery.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn try_as_string(&self) -> Result<String, QueryError> {
+        let mut ctx = Context::literal(self.dialect).rendered_by(self.renderer);
+        let sql = self.render(&mut ctx);
+
+        ctx.finish(sql)
+    }
+
+    /// Checks whether the [`InsertQuery`] can be rendered for its [`Dialect`]
+    ///
+    /// [`InsertQuery`]: ./struct.InsertQuery.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn validate(&self) -> Result<(), QueryError> {
+        self.try_as_string().map(|_| ())
+    }
+
+    /// Checks that no column is set more than once when ignoring case
+    ///
+    /// Most databases treat `Name` and `name` as the same column and reject a statement
+    /// that uses both. This check is not part of [`validate`], as some databases do
+    /// have case sensitive column names.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{InsertQuery, Value, QueryError};
+    ///
+    /// let mut query = InsertQuery::into("users");
+    /// query.values.insert("Name", Value::Varchar("anna"));
+    /// assert!(query.check_duplicate_columns().is_ok());
+    ///
+    /// query.values.insert("name", Value::Varchar("bert"));
+    /// assert_eq!(
+    ///     query.check_duplicate_columns(),
+    ///     Err(QueryError::DuplicateColumn { column: "name".to_string() })
+    /// );
+    /// ```
+    ///
+    /// [`validate`]: #method.validate
+    pub fn check_duplicate_columns(&self) -> Result<(), QueryError> {
+        check_duplicate_columns(self.values.keys().cloned())
+    }
+
+    /// Declares that `column` holds at most `max_length` characters, like a `VARCHAR(max_length)`
+    ///
+    /// Text values that are longer make [`try_as_string`] and [`validate`] fail, instead
+    /// of the database rejecting the statement later on.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{InsertQuery, Value, QueryError};
+    ///
+    /// let mut query = InsertQuery::into("users");
+    /// query.max_length("name", 4);
+    ///
+    /// query.values.insert("name", Value::Varchar("anna"));
+    /// assert_eq!(query.try_as_string(), Ok("INSERT INTO users(name) VALUES('anna')".to_string()));
+    ///
+    /// query.values.insert("name", Value::Varchar("annabel"));
+    /// assert_eq!(
+    ///     query.validate(),
+    ///     Err(QueryError::TooLong { column: "name".to_string(), max_length: 4 })
+    /// );
+    /// ```
+    ///
+    /// [`try_as_string`]: #method.try_as_string
+    /// [`validate`]: #method.validate
+    pub fn max_length(&mut self, column: &'a str, max_length: usize) {
+        self.max_lengths.insert(column, max_length);
+    }
+
+    /// Adds another row to insert after the one in `values`
+    ///
+    /// The columns of the statement are the ones of `values`. Columns the row leaves
+    /// out are set to `DEFAULT`, while columns `values` does not have make
+    /// [`try_as_string`] and [`validate`] fail.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{InsertQuery, Value};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut query = InsertQuery::into("users");
+    /// query.values.insert("name", Value::Varchar("anna"));
+    /// query.values.insert("age", Value::Int(31));
+    ///
+    /// let mut row = BTreeMap::new();
+    /// row.insert("name", Value::Varchar("bert"));
+    /// query.add_row(row);
+    ///
+    /// assert_eq!(query.as_string(), "INSERT INTO users(age, name) VALUES(31, 'anna'), (DEFAULT, 'bert')");
+    /// ```
+    ///
+    /// [`try_as_string`]: #method.try_as_string
+    /// [`validate`]: #method.validate
+    pub fn add_row(&mut self, row: BTreeMap<&'a str, Value<'a>>) {
+        self.rows.push(row);
+    }
+
+    /// Sets how the rows are laid out if there is more than one, see [`RowLayout`]
+    ///
+    /// [`RowLayout`]: ./enum.RowLayout.html
+    pub fn row_layout(&mut self, layout: RowLayout) {
+        self.row_layout = layout;
+    }
+
+    /// Returns the number of rows the query inserts, the one in `values` included
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{InsertQuery, Value};
+    ///
+    /// let mut query = InsertQuery::into("users");
+    /// assert_eq!(query.row_count(), 0);
+    ///
+    /// query.values.insert("name", Value::Varchar("anna"));
+    /// assert_eq!(query.row_count(), 1);
+    /// ```
+    pub fn row_count(&self) -> usize {
+        if self.values.is_empty() && self.rows.is_empty() {
+            0
+        } else {
+            1 + self.rows.len()
+        }
+    }
+
+    /// Splits `rows` into inserts into `table` of at most `batch_size` rows each
+    ///
+    /// The rows are only taken from `rows` when the next insert is built, so a large
+    /// dataset never has to be held in memory as a whole. A `batch_size` of 0 is treated as 1.
+    /// All rows should set the same columns, see [`add_row`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{InsertQuery, Value};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let rows = (1..6).map(|id| {
+    ///     let mut row = BTreeMap::new();
+    ///     row.insert("id", Value::Int(id));
+    ///     row
+    /// });
+    ///
+    /// let sql: Vec<String> = InsertQuery::batches("numbers", rows, 2).map(|q| q.as_string()).collect();
+    /// assert_eq!(sql, vec![
+    ///     "INSERT INTO numbers(id) VALUES(1), (2)",
+    ///     "INSERT INTO numbers(id) VALUES(3), (4)",
+    ///     "INSERT INTO numbers(id) VALUES(5)",
+    /// ]);
+    /// ```
+    ///
+    /// [`add_row`]: #method.add_row
+    pub fn batches<I>(table: &'a str, rows: I, batch_size: usize) -> InsertBatches<'a, I::IntoIter>
+        where I: IntoIterator<Item = BTreeMap<&'a str, Value<'a>>> {
+        InsertBatches {
+            table,
+            rows: rows.into_iter(),
+            batch_size: batch_size.max(1),
+            dialect: Dialect::Generic,
+        }
+    }
+
+    /// Annotates the columns of `spec` with their [`Nullability`], e.g. for reviewing generated seed scripts
+    ///
+    /// The annotations are written as comments after the columns, so they do not change
+    /// what the statement does. Columns that are not part of `spec` are left as they are.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{InsertQuery, Value, Nullability};
+    ///
+    /// let mut query = InsertQuery::into("users");
+    /// query.values.insert("name", Value::Varchar("anna"));
+    /// query.values.insert("bio", Value::Null);
+    /// query.annotate_nullability(&[("name", Nullability::NotNull), ("bio", Nullability::Nullable)]);
+    ///
+    /// assert_eq!(query.as_string(), "INSERT INTO users(bio /* NULL */, name /* NOT NULL */) VALUES(NULL, 'anna')");
+    /// ```
+    ///
+    /// [`Nullability`]: ./enum.Nullability.html
+    pub fn annotate_nullability(&mut self, spec: &[(&'a str, Nullability)]) {
+        self.nullability.extend(spec.iter().cloned());
+    }
+
+    /// Renders the query, passing all values through `ctx`
+    fn render(&self, ctx: &mut Context<'a>) -> String {
+        ctx.choose_case(self.keyword_case);
+        let mut res = ctx.case().format("INSERT INTO {}(", &[&self.into]);
+        let columns: Vec<String> = self.values.keys().map(|k| match self.nullability.get(k) {
+            Some(n) => format!("{} /* {} */", k, n),
+            None => k.to_string(),
+        }).collect();
+        res.push_str(&columns.join(", "));
+        res.push_str(&ctx.kw(") VALUES"));
+
+        let mut groups = vec![self.render_row(&self.values, ctx)];
+        for row in &self.rows {
+            if let Some(column) = row.keys().find(|c| !self.values.contains_key(*c)) {
+                ctx.errors.push(QueryError::UnexpectedColumn { column: column.to_string() });
+            }
+            groups.push(self.render_row(row, ctx));
+        }
+
+        match self.row_layout {
+            RowLayout::Expanded if groups.len() > 1 => {
+                res.push_str("\n    ");
+                res.push_str(&groups.join(",\n    "));
+            },
+            _ => res.push_str(&groups.join(", ")),
+        }
+        res
+    }
+
+    /// Renders the parenthesized values `row` has for the columns of the query
+    fn render_row(&self, row: &BTreeMap<&'a str, Value<'a>>, ctx: &mut Context<'a>) -> String {
+        let values: Vec<String> = self.values.keys().map(|k| match row.get(k) {
+            Some(v) => {
+                if let Some(&max) = self.max_lengths.get(k) {
+                    ctx.check_length(k, v, max);
+                }
+                ctx.value(k, v)
+            },
+            None => ctx.value(k, &Value::Default),
+        }).collect();
+
+        format!("({})", values.join(", "))
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Iterator over the [`InsertQuery`]s that load a sequence of rows in batches
+///
+/// It is created with [`InsertQuery::batches`].
+///
+/// [`InsertQuery`]: ./struct.InsertQuery.html
+/// [`InsertQuery::batches`]: ./struct.InsertQuery.html#method.batches
+pub struct InsertBatches<'a, I> {
+    table: &'a str,
+    rows: I,
+    batch_size: usize,
+    dialect: Dialect,
+}
+
+impl<'a, I> InsertBatches<'a, I> {
+    /// Sets the [`Dialect`] of all inserts
+    ///
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+}
+
+impl<'a, I> Iterator for InsertBatches<'a, I> where I: Iterator<Item = BTreeMap<&'a str, Value<'a>>> {
+    type Item = InsertQuery<'a>;
+
+    fn next(&mut self) -> Option<InsertQuery<'a>> {
+        let mut query = InsertQuery::into(self.table);
+        query.dialect(self.dialect);
+        query.values = self.rows.next()?;
+        for row in self.rows.by_ref().take(self.batch_size - 1) {
+            query.add_row(row);
+        }
+        Some(query)
+    }
+}
+
+#[derive(Debug)]
+/// Struct representing a SQL Delete Statement
+pub struct DeleteQuery<'a> {
+    from: &'a str,
+    pub whre: WhereList<'a>,
+    scope: Vec<WhereClause<'a>>,
+    conds: Vec<Cond<'a>>,
+    limit: Option<usize>,
+    order_by: Option<OrderBy<'a>>,
+    only: bool,
+    partitions: Vec<&'a str>,
+    returning: Vec<SelectItem<'a>>,
+    renames: Vec<(&'a str, &'a str)>,
+    max_conditions: Option<usize>,
+    keyword_case: KeywordCase,
+    renderer: Option<&'a dyn ValueRenderer>,
+    dialect: Dialect,
+}
+
+impl<'a> Display for DeleteQuery<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+impl<'a> DeleteQuery<'a> {
+    /// Return a new [`DeleteQuery`] that deletes data from table `table`
+    /// 
+    /// [`DeleteQuery`]: ./struct.DeleteQuery.html
+    pub fn from(table: &'a str) -> DeleteQuery<'a> {
+        DeleteQuery {
+            from: table,
+            whre: WhereList::new(),
+            scope: Vec::new(),
+            conds: Vec::new(),
+            limit: None,
+            order_by: None,
+            only: false,
+            partitions: Vec::new(),
+            returning: Vec::new(),
+            renames: Vec::new(),
+            max_conditions: None,
+            keyword_case: KeywordCase::Upper,
+            renderer: None,
+            dialect: Dialect::Generic,
+        }
+    }
+
+    /// Returns a new [`DeleteQuery`] for the table `select` reads from
+    ///
+    /// The dialect, table renames, `ONLY` and partitions are taken over from `select`. If
+    /// `with_filters` is set, the WHERE-clauses and scopes are copied as well, so the query
+    /// deletes exactly the rows `select` finds. Returns `None` if `select` does not read from
+    /// a single table without any joins.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, SelectQuery, WhereClause, Value};
+    ///
+    /// let mut select = SelectQuery::select(&["id"]).from("sessions");
+    /// select.whre.push(WhereClause::new("expired", Value::Bool(true)));
+    ///
+    /// let delete = DeleteQuery::from_same_table_as(&select, true).unwrap();
+    /// assert_eq!(delete.as_string(), "DELETE FROM sessions WHERE expired = TRUE");
+    /// ```
+    ///
+    /// [`DeleteQuery`]: ./struct.DeleteQuery.html
+    pub fn from_same_table_as(select: &SelectQuery<'a>, with_filters: bool) -> Option<DeleteQuery<'a>> {
+        let table = match select.from.as_slice() {
+            [FromItem::Table(t)] if select.joins.is_empty() && select.raw.is_none() => *t,
+            _ => return None,
+        };
+
+        let mut q = DeleteQuery::from(table);
+        q.only = select.only;
+        q.partitions = select.partitions.clone();
+        q.renames = select.renames.clone();
+        q.dialect = select.dialect;
+        if with_filters {
+            q.whre = select.whre.clone();
+            q.scope = select.scope.clone();
+            q.conds = select.conds.clone();
+        }
+        Some(q)
+    }
+
+    /// Restricts the query to the named table itself, excluding tables inheriting from it
+    ///
+    /// This renders the `ONLY` keyword before the table, which is only supported by Postgres.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Dialect};
+    ///
+    /// let mut query = DeleteQuery::from("cities");
+    /// query.only();
+    /// query.dialect(Dialect::Postgres);
+    ///
+    /// assert_eq!(query.as_string(), "DELETE FROM ONLY cities");
+    /// ```
+    pub fn only(&mut self) {
+        self.only = true;
+    }
+
+    /// Restricts the query to the `partitions` of a partitioned table
+    ///
+    /// This renders a `PARTITION` clause after the table, which is only supported by MySQL.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Dialect};
+    ///
+    /// let mut query = DeleteQuery::from("logs");
+    /// query.partition(&["p0", "p1"]);
+    /// query.dialect(Dialect::MySql);
+    ///
+    /// assert_eq!(query.as_string(), "DELETE FROM logs PARTITION (p0, p1)");
+    /// ```
+    pub fn partition(&mut self, partitions: &[&'a str]) {
+        self.partitions = partitions.to_vec();
+    }
+
+    /// Makes the query return the `columns` of the deleted rows
+    ///
+    /// This renders a `RETURNING` clause, which is only supported by Postgres.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Value, WhereClause, Dialect};
+    ///
+    /// let mut query = DeleteQuery::from("users");
+    /// query.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    /// query.returning(&["id", "email"]);
+    /// query.dialect(Dialect::Postgres);
+    ///
+    /// assert_eq!(query.as_string(), "DELETE FROM users WHERE name = 'anna' RETURNING id, email");
+    /// ```
+    pub fn returning(&mut self, columns: &[&'a str]) {
+        self.returning = columns.iter().map(|c| SelectItem::Column(c)).collect();
+    }
+
+    /// Adds the expression `expr` to the `RETURNING` clause, returned under the name `alias`
+    ///
+    /// Like [`returning`], this is only supported by Postgres. The expression is
+    /// written as it is, so it must not contain user input.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Dialect};
+    ///
+    /// let mut query = DeleteQuery::from("sessions");
+    /// query.returning(&["id"]);
+    /// query.returning_as("now() - created_at", "age");
+    /// query.dialect(Dialect::Postgres);
+    ///
+    /// assert_eq!(query.as_string(), "DELETE FROM sessions RETURNING id, now() - created_at AS age");
+    /// ```
+    ///
+    /// [`returning`]: #method.returning
+    pub fn returning_as(&mut self, expr: &'a str, alias: &'a str) {
+        self.returning.push(SelectItem::Aliased(expr, alias));
+    }
+
+    /// Makes the query return all columns of the deleted rows, e.g. to log them
+    ///
+    /// Like [`returning`], this is only supported by Postgres.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Dialect};
+    ///
+    /// let mut query = DeleteQuery::from("sessions");
+    /// query.returning_all();
+    /// query.dialect(Dialect::Postgres);
+    ///
+    /// assert_eq!(query.as_string(), "DELETE FROM sessions RETURNING *");
+    /// ```
+    ///
+    /// [`returning`]: #method.returning
+    pub fn returning_all(&mut self) {
+        self.returning = vec![SelectItem::Column("*")];
+    }
+
+    /// Sets the limit of items to delete
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::{DeleteQuery, Value, WhereClause};
+    /// 
+    /// let mut query = DeleteQuery::from("users");
+    /// // add values to delete
+    /// query.whre.push(WhereClause::new("name", Value::Varchar("gregory")));
+    /// 
+    /// // add the limit
+    /// query.limit(1);
+    /// 
+    /// // make sure the query looks like expected
+    /// assert_eq!(query.as_string(), "DELETE FROM users WHERE name = 'gregory' LIMIT 1");
+    /// ```
+    pub fn limit(&mut self, limit: usize) {
+        self.limit = Some(limit);
+    }
+
+    /// Returns the limit of the [`DeleteQuery`]
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::{DeleteQuery, Value};
+    /// 
+    /// // create query
+    /// let mut query = DeleteQuery::from("users");
+    /// 
+    /// // set the limit
+    /// query.limit(12);
+    /// 
+    /// assert_eq!(query.get_limit(), Some(12));
+    /// ```
+    /// 
+    /// [`DeleteQuery`]: ./struct.DeleteQuery.html
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Removes the limit from the [`DeleteQuery`]
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::DeleteQuery;
+    /// 
+    /// let mut query = DeleteQuery::from("users");
+    /// /* add limit to the query */
+    /// query.limit(12);
+    /// 
+    /// assert_eq!(query.get_limit(), Some(12));
+    /// 
+    /// /* clear the limit */
+    /// query.clear_limit();
+    /// 
+    /// assert_eq!(query.get_limit(), None);
+    /// 
+    /// ```
+    /// [`DeleteQuery`]: ./struct.DeleteQuery.html
+    pub fn clear_limit(&mut self) {
+        self.limit = None;
+    }
+
+    /// Adds a [`OrderBy`] clause to the query
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::{DeleteQuery, OrderBy};
+    /// 
+    /// let mut query = DeleteQuery::from("continents");
+    /// query.order_by(OrderBy::Row("population"));
+    /// 
+    /// assert_eq!(query.as_string(), "DELETE FROM continents ORDER BY population");
+    /// ```
+    /// [`OrderBy`]: ./enum.OrderBy.html
+    pub fn order_by(&mut self, ob: OrderBy<'a>) {
+        self.order_by = Some(ob);
+    }
+
+    /// Returns either `true` or `false` depending on whether
+    /// or not the [`DeleteQuery`] contains a [`OrderBy`] clause
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::{DeleteQuery, OrderBy};
+    /// 
+    /// let mut query = DeleteQuery::from("states");
+    /// query.order_by(OrderBy::Row("population"));
+    /// 
+    /// assert!(query.is_ordered());
+    /// ```
+    /// [`DeleteQuery`]: ./struct.DeleteQuery.html
+    /// [`OrderBy`]: ./enum.OrderBy.html
+    pub fn is_ordered(&self) -> bool {
+        self.order_by.is_some()
+    }
+
+    /// Removes the ORDER BY clause from the query
+    pub fn clear_order_by(&mut self) {
+        self.order_by = None;
+    }
+
+    /// Restricts the query to rows where `column` equals `value`
+    ///
+    /// Unlike the clauses in `whre`, a scope can not be removed from the query again
+    /// and is always AND-combined with all other conditions. This makes it suitable
+    /// for mandatory filters such as tenant isolation.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Value, WhereClause, Condition};
+    ///
+    /// let mut query = DeleteQuery::from("users");
+    /// query.scope("tenant_id", Value::Int(7));
+    /// query.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    /// query.whre.push_or(WhereClause::new("name", Value::Varchar("bert")));
+    ///
+    /// assert_eq!(query.as_string(), "DELETE FROM users WHERE tenant_id = 7 AND (name = 'anna' OR name = 'bert')");
+    /// ```
+    pub fn scope(&mut self, column: &'a str, value: Value<'a>) {
+        self.scope.push(WhereClause::new(column, value));
+    }
+
+    /// Works like [`SelectQuery::where_cond`]
+    ///
+    /// [`SelectQuery::where_cond`]: ./struct.SelectQuery.html#method.where_cond
+    pub fn where_cond(&mut self, cond: Cond<'a>) {
+        self.conds.push(cond);
+    }
+
+    /// Works like [`SelectQuery::and_where`]
+    ///
+    /// [`SelectQuery::and_where`]: ./struct.SelectQuery.html#method.and_where
+    pub fn and_where(&mut self, clause: WhereClause<'a>) {
+        self.whre.push(clause);
+    }
+
+    /// Works like [`SelectQuery::or_where`]
+    ///
+    /// [`SelectQuery::or_where`]: ./struct.SelectQuery.html#method.or_where
+    pub fn or_where(&mut self, clause: WhereClause<'a>) {
+        self.whre.push_or(clause);
+    }
+
+    /// Excludes soft-deleted rows, i.e. rows where `column` is not `NULL`
+    ///
+    /// Like a [`scope`] the filter can not be removed again and is AND-combined
+    /// with all other conditions.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Value, WhereClause};
+    ///
+    /// let mut query = DeleteQuery::from("users");
+    /// query.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    /// query.exclude_deleted("deleted_at");
+    ///
+    /// assert_eq!(query.as_string(), "DELETE FROM users WHERE deleted_at IS NULL AND name = 'anna'");
+    /// ```
+    ///
+    /// [`scope`]: #method.scope
+    pub fn exclude_deleted(&mut self, column: &'a str) {
+        self.scope.push(WhereClause::with_operator(column, Operator::Is, Value::Null));
+    }
+
+    /// Sorts the [`WhereClause`]s of the query into a deterministic order
+    ///
+    /// Queries with the same conditions render to the same SQL after this, no matter
+    /// in which order the conditions were added. The clauses are only reordered if they
+    /// are all combined with the same [`Condition`], otherwise reordering could change
+    /// which rows match and the query is left as it is.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Value, WhereClause};
+    ///
+    /// let mut a = DeleteQuery::from("users");
+    /// a.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    /// a.whre.push(WhereClause::new("age", Value::Int(30)));
+    ///
+    /// let mut b = DeleteQuery::from("users");
+    /// b.whre.push(WhereClause::new("age", Value::Int(30)));
+    /// b.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    ///
+    /// a.canonicalize();
+    /// b.canonicalize();
+    /// assert_eq!(a.as_string(), b.as_string());
+    /// ```
+    ///
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    /// [`Condition`]: ./enum.Condition.html
+    pub fn canonicalize(&mut self) {
+        canonicalize(&mut self.whre);
+    }
+
+    /// Renders the query against the table `new` instead of `old`
+    ///
+    /// This replaces `old` wherever it is used as a table, as well as in every column
+    /// qualified with it (`old.column`), including column references in values and
+    /// subqueries. Raw SQL fragments like [`JoinCondition::On`] are left untouched.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Value, WhereClause};
+    ///
+    /// let mut q = DeleteQuery::from("users");
+    /// q.whre.push(WhereClause::new("users.id", Value::Int(3)));
+    /// q.rename_table("users", "tmp_users");
+    ///
+    /// assert_eq!(q.as_string(), "DELETE FROM tmp_users WHERE tmp_users.id = 3");
+    /// ```
+    ///
+    /// [`JoinCondition::On`]: ./enum.JoinCondition.html#variant.On
+    pub fn rename_table(&mut self, old: &'a str, new: &'a str) {
+        self.renames.push((old, new));
+    }
+
+    /// Return a [`String`] representing the [`DeleteQuery`]
+    /// 
+    /// ## Example
+    /// ```
+    /// use query_builder::{DeleteQuery, Value, WhereClause, Condition};
+    /// 
+    /// // create basic query
+    /// let mut query = DeleteQuery::from("people");
+    /// 
+    /// // set parameter of the query
+    /// query.whre.push(WhereClause::new("name", Value::Varchar("justine")));
+    /// query.whre.push(WhereClause::new("age", Value::Int(24)));
+    /// query.limit(1);
+    /// 
+    /// assert_eq!(query.as_string(), "DELETE FROM people WHERE name = 'justine' AND age = 24 LIMIT 1");
+    /// ```
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn as_string(&self) -> String {
+        let mut res = String::new();
+        /* writing into a String never fails */
+        self.write_to(&mut res).unwrap();
+        res
+    }
+
+    /// Writes the SQL of [`as_string`] into `w` instead of returning a new [`String`]
+    ///
+    /// This allows reusing one buffer for rendering many queries.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::DeleteQuery;
+    ///
+    /// let query = DeleteQuery::from("users");
+    ///
+    /// let mut buf = String::new();
+    /// query.write_to(&mut buf).unwrap();
+    /// assert_eq!(buf, query.as_string());
+    /// ```
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn write_to<W: Write>(&self, w: &mut W) -> FormatResult {
+        w.write_str(&self.render(&mut Context::literal(self.dialect).rendered_by(self.renderer)))
+    }
+
+    /// Returns the query with a placeholder for every value, along with
+    /// the values in the order of their placeholders
+    ///
+    /// Placeholders are numbered (`$1`, `$2`, ...) for [`Dialect::Postgres`] and
+    /// `?` for all other dialects. Column references and intervals are part of
+    /// the statement and are never replaced.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Value, WhereClause};
+    ///
+    /// let mut query = DeleteQuery::from("users");
+    /// query.whre.push(WhereClause::new("age", Value::Int(30)));
+    ///
+    /// let (sql, values) = query.as_parameterized();
+    /// assert_eq!(sql, "DELETE FROM users WHERE age = ?");
+    /// assert_eq!(values, vec![Value::Int(30)]);
+    /// ```
+    ///
+    /// [`Dialect::Postgres`]: ./enum.Dialect.html#variant.Postgres
+    pub fn as_parameterized(&self) -> (String, Vec<Value<'a>>) {
+        self.as_parameterized_from(1)
+    }
+
+    /// Works like [`as_parameterized`], but numbers the placeholders from `start_index` on
+    ///
+    /// See [`SelectQuery::as_parameterized_from`].
+    ///
+    /// [`as_parameterized`]: #method.as_parameterized
+    /// [`SelectQuery::as_parameterized_from`]: ./struct.SelectQuery.html#method.as_parameterized_from
+    pub fn as_parameterized_from(&self, start_index: usize) -> (String, Vec<Value<'a>>) {
+        let mut ctx = Context::placeholders(self.dialect).starting_at(start_index);
+        let sql = self.render(&mut ctx);
+
+        (sql, ctx.into_values())
+    }
+
+    /// Returns the query with all values written out, for logging only
+    ///
+    /// Works like [`SelectQuery::as_debug_string`].
+    ///
+    /// [`SelectQuery::as_debug_string`]: ./struct.SelectQuery.html#method.as_debug_string
+    pub fn as_debug_string(&self) -> String {
+        self.render(&mut Context::escaped(self.dialect).rendered_by(self.renderer))
+    }
+
+    /// Returns the query like [`as_string`] along with [`Warning`]s about likely mistakes
+    ///
+    /// Works like [`SelectQuery::render_with_warnings`].
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`Warning`]: ./enum.Warning.html
+    /// [`SelectQuery::render_with_warnings`]: ./struct.SelectQuery.html#method.render_with_warnings
+    pub fn render_with_warnings(&self) -> (String, Vec<Warning>) {
+        let mut warnings = Vec::new();
+        if self.scope.is_empty() && self.whre.is_empty() && self.conds.is_empty() {
+            warnings.push(Warning::NoWhere);
+        }
+        if self.limit.is_some() && self.order_by.is_none() {
+            warnings.push(Warning::UnorderedLimit);
+        }
+        (self.as_string(), warnings)
+    }
+
+    /// Returns the query with a placeholder for every value, along with the values
+    ///
+    /// Works like [`SelectQuery::into_prepared`].
+    ///
+    /// [`SelectQuery::into_prepared`]: ./struct.SelectQuery.html#method.into_prepared
+    pub fn into_prepared(mut self) -> (String, Vec<Value<'a>>) {
+        self.canonicalize();
+        self.as_parameterized()
+    }
+
+    /// Returns only the WHERE clause of the query with placeholders, along with
+    /// the values in the order of their placeholders
+    ///
+    /// This is meant for code that builds the rest of the statement itself. The
+    /// clause includes the [`scope`] conditions, and is empty if there are no conditions.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Value, WhereClause};
+    ///
+    /// let mut query = DeleteQuery::from("users");
+    /// query.whre.push(WhereClause::new("age", Value::Int(30)));
+    /// query.whre.push(WhereClause::new("city", Value::Varchar("berlin")));
+    ///
+    /// let (sql, values) = query.where_parameterized();
+    /// assert_eq!(sql, "WHERE age = ? AND city = ?");
+    /// assert_eq!(values, vec![Value::Int(30), Value::Varchar("berlin")]);
+    /// ```
+    ///
+    /// [`scope`]: #method.scope
+    pub fn where_parameterized(&self) -> (String, Vec<Value<'a>>) {
+        let mut ctx = Context::placeholders(self.dialect);
+        let sql = Clauses {
+            scope: &self.scope,
+            whre: &self.whre.entries,
+            conds: &self.conds,
+            group_by: None,
+            having: &[],
+            having_raw: &[],
+            order_by: None,
+            limit: None,
+            offset: None,
+            bind_limit: false,
+            max_conditions: self.max_conditions,
+        }.where_clause(&mut ctx).unwrap_or_default();
+
+        (sql, ctx.into_values())
+    }
+
+    /// Returns the values that [`as_parameterized`] binds, each paired with the
+    /// column it is compared with or assigned to
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Value, WhereClause};
+    ///
+    /// let mut query = DeleteQuery::from("users");
+    /// query.whre.push(WhereClause::new("age", Value::Int(30)));
+    ///
+    /// assert_eq!(query.parameter_manifest(), vec![("age".to_string(), Value::Int(30))]);
+    /// ```
+    ///
+    /// [`as_parameterized`]: #method.as_parameterized
+    pub fn parameter_manifest(&self) -> Vec<(String, Value<'a>)> {
+        let mut ctx = Context::placeholders(self.dialect);
+        self.render(&mut ctx);
+
+        ctx.bound
+    }
+
+    /// Returns every value written into the query, in the order they appear in it
+    ///
+    /// This includes the values of subqueries and does not depend on whether the
+    /// query is parameterized, which makes it useful for auditing. Lists are replaced
+    /// by their items, column references are left out as they are not values.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Value, WhereClause};
+    ///
+    /// let mut query = DeleteQuery::from("users");
+    /// query.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    ///
+    /// assert_eq!(query.collect_values(), vec![&Value::Varchar("anna")]);
+    /// ```
+    pub fn collect_values(&self) -> Vec<&Value<'a>> {
+        let mut values = Vec::new();
+        collect_clauses(&self.scope, &mut values);
+        collect_clauses(self.whre.clauses(), &mut values);
+        for c in &self.conds {
+            collect_conds(c, &mut values);
+        }
+        values
+    }
+
+    /// Sets the [`Dialect`] the query is rendered for
+    ///
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn dialect(&mut self, dialect: Dialect) {
+        self.dialect = dialect;
+    }
+
+    /// Sets the [`ValueRenderer`] used for the values written into the query,
+    /// instead of the one of its [`Dialect`]
+    ///
+    /// [`ValueRenderer`]: ./trait.ValueRenderer.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn renderer(&mut self, renderer: &'a dyn ValueRenderer) {
+        self.renderer = Some(renderer);
+    }
+
+    /// Sets whether the keywords of the query are written in upper or lower case
+    ///
+    /// [`KeywordCase::Upper`] is used unless told otherwise.
+    ///
+    /// [`KeywordCase::Upper`]: ./enum.KeywordCase.html#variant.Upper
+    pub fn keyword_case(&mut self, case: KeywordCase) {
+        self.keyword_case = case;
+    }
+
+    /// Limits the query to at most `max` conditions, as a guard against accidentally huge queries
+    ///
+    /// Every [`WhereClause`] counts as one condition, except for `IN` lists, which count
+    /// every value. [`try_as_string`] and [`validate`] fail if there are more.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Value, WhereClause, Operator, QueryError};
+    ///
+    /// let mut query = DeleteQuery::from("users");
+    /// query.max_conditions(2);
+    /// query.whre.push(WhereClause::with_operator("id", Operator::In, Value::List(vec![Value::Int(1), Value::Int(2), Value::Int(3)])));
+    ///
+    /// assert_eq!(query.validate(), Err(QueryError::TooManyConditions { count: 3, max: 2 }));
+    /// ```
+    ///
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    /// [`try_as_string`]: #method.try_as_string
+    /// [`validate`]: #method.validate
+    pub fn max_conditions(&mut self, max: usize) {
+        self.max_conditions = Some(max);
+    }
+
+    /// Returns the [`String`] representation of the [`DeleteQuery`], or an error if
+    /// the query uses features its [`Dialect`] does not support
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{DeleteQuery, Value, WhereClause, Dialect};
+    ///
+    /// let mut query = DeleteQuery::from("users");
+    /// query.whre.push(WhereClause::new("tags", Value::Array(vec![Value::Int(1)])));
+    /// assert!(query.try_as_string().is_err());
+    ///
+    /// query.dialect(Dialect::Postgres);
+    /// assert!(query.try_as_string().is_ok());
+    /// ```
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`DeleteQuery`]: ./struct.DeleteQuery.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn try_as_string(&self) -> Result<String, QueryError> {
+        let mut ctx = Context::literal(self.dialect).rendered_by(self.renderer);
+        let sql = self.render(&mut ctx);
+
+        ctx.finish(sql)
+    }
+
+    /// Checks whether the [`DeleteQuery`] can be rendered for its [`Dialect`]
+    ///
+    /// [`DeleteQuery`]: ./struct.DeleteQuery.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn validate(&self) -> Result<(), QueryError> {
+        self.try_as_string().map(|_| ())
+    }
+
+    /// Renders the query, passing all values through `ctx`
+    fn render(&self, ctx: &mut Context<'a>) -> String {
+        ctx.choose_case(self.keyword_case);
+        let renamed = ctx.push_renames(&self.renames);
+        let mut res = ctx.case().format("DELETE FROM {}{}", &[&only(self.only, ctx), &ctx.ident(self.from)]);
+        res.push_str(&partition(&self.partitions, ctx));
+
+        Clauses {
+            scope: &self.scope,
+            whre: &self.whre.entries,
+            conds: &self.conds,
+            group_by: None,
+            having: &[],
+            having_raw: &[],
+            order_by: self.order_by.as_ref(),
+            limit: self.limit,
+            offset: None,
+            bind_limit: false,
+            max_conditions: self.max_conditions,
+        }.append_to(&mut res, ctx);
+
+        if !self.returning.is_empty() {
+            ctx.require("RETURNING", &[Dialect::Postgres]);
+            res.push_str(&ctx.kw(" RETURNING "));
+            let returning: Vec<String> = self.returning.iter().map(|r| r.render(ctx)).collect();
+            res.push_str(&returning.join(", "));
+        }
+
+        ctx.renames.truncate(renamed);
+        res
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+/// Map of columns to their values that keeps the columns in the order they were first inserted
+///
+/// It offers the parts of the [`BTreeMap`] API the queries need.
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{ColumnMap, Value};
+///
+/// let mut map = ColumnMap::new();
+/// map.insert("name", Value::Varchar("anna"));
+/// map.insert("age", Value::Int(30));
+/// map.insert("name", Value::Varchar("bert"));
+///
+/// let columns: Vec<&str> = map.keys().cloned().collect();
+/// assert_eq!(columns, vec!["name", "age"]);
+/// assert_eq!(map.get("name"), Some(&Value::Varchar("bert")));
+/// ```
+///
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+pub struct ColumnMap<'a> {
+    entries: Vec<(&'a str, Value<'a>)>,
+}
+
+impl<'a> ColumnMap<'a> {
+    /// Returns a new, empty [`ColumnMap`]
+    ///
+    /// [`ColumnMap`]: ./struct.ColumnMap.html
+    pub fn new() -> ColumnMap<'a> {
+        ColumnMap {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Sets `column` to `value`, returning the value it had before
+    ///
+    /// A column that is already in the map keeps its position.
+    pub fn insert(&mut self, column: &'a str, value: Value<'a>) -> Option<Value<'a>> {
+        match self.entries.iter_mut().find(|e| e.0 == column) {
+            Some(e) => Some(::std::mem::replace(&mut e.1, value)),
+            None => {
+                self.entries.push((column, value));
+                None
+            },
+        }
+    }
+
+    /// Returns the value of `column`
+    pub fn get(&self, column: &str) -> Option<&Value<'a>> {
+        self.entries.iter().find(|e| e.0 == column).map(|e| &e.1)
+    }
+
+    /// Returns whether the map contains `column`
+    pub fn contains_key(&self, column: &str) -> bool {
+        self.get(column).is_some()
+    }
+
+    /// Removes `column` from the map, returning its value
+    pub fn remove(&mut self, column: &str) -> Option<Value<'a>> {
+        let pos = self.entries.iter().position(|e| e.0 == column)?;
+        Some(self.entries.remove(pos).1)
+    }
+
+    /// Returns the number of columns in the map
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the map has no columns
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the columns and their values in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (&&'a str, &Value<'a>)> {
+        self.entries.iter().map(|e| (&e.0, &e.1))
+    }
+
+    /// Returns the columns in insertion order
+    pub fn keys(&self) -> impl Iterator<Item = &&'a str> {
+        self.entries.iter().map(|e| &e.0)
+    }
+
+    /// Returns the values in the insertion order of their columns
+    pub fn values(&self) -> impl Iterator<Item = &Value<'a>> {
+        self.entries.iter().map(|e| &e.1)
+    }
+}
+
+impl<'a, 'k> Index<&'k str> for ColumnMap<'a> {
+    type Output = Value<'a>;
+
+    /// Returns the value of `column`
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the column is not in the map.
+    fn index(&self, column: &'k str) -> &Value<'a> {
+        self.get(column).expect("no such column in the map")
+    }
+}
+
+#[derive(Debug)]
+/// Struct representing an SQL Update statement
+pub struct UpdateQuery<'a> {
+    update: &'a str,
+    /// A Map containing the field to set with the appropiate values to them
+    ///
+    /// The assignments are rendered in the order the columns were first inserted.
+    /// Standard SQL evaluates every value against the row as it was before the
+    /// update, so `a = b, b = a` swaps two columns. MySQL however evaluates them
+    /// from left to right and sees the columns that were already assigned.
+    pub set: ColumnMap<'a>,
+    max_lengths: BTreeMap<&'a str, usize>,
+    /// All [`WhereClause`]s for conditional Updating in this 
+    /// [`UpdateQuery`]
+    /// 
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    /// [`UpdateQuery`]: ./struct.UpdateQuery.html
+    pub whre: WhereList<'a>,
+    scope: Vec<WhereClause<'a>>,
+    conds: Vec<Cond<'a>>,
+    limit: Option<usize>,
+    order_by: Option<OrderBy<'a>>,
+    only: bool,
+    partitions: Vec<&'a str>,
+    renames: Vec<(&'a str, &'a str)>,
+    max_conditions: Option<usize>,
+    keyword_case: KeywordCase,
+    renderer: Option<&'a dyn ValueRenderer>,
+    dialect: Dialect,
+}
+
+impl<'a> Display for UpdateQuery<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+impl<'a> UpdateQuery<'a> {
+    /// Returns a new [`UpdateQuery`] that updates the table `table`
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::UpdateQuery;
+    /// 
+    /// let query = UpdateQuery::update("town");
+    /// 
+    /// assert_eq!(query.as_string(), "UPDATE town");
+    /// ```
+    /// 
+    /// [`UpdateQuery`]: ./struct.UpdateQuery.html
+    pub fn update(table: &'a str) -> UpdateQuery<'a> {
+        UpdateQuery {
+            update: table,
+            set: ColumnMap::new(),
+            max_lengths: BTreeMap::new(),
+            whre: WhereList::new(),
+            scope: Vec::new(),
+            conds: Vec::new(),
+            limit: None,
+            order_by: None,
+            only: false,
+            partitions: Vec::new(),
+            renames: Vec::new(),
+            max_conditions: None,
+            keyword_case: KeywordCase::Upper,
+            renderer: None,
+            dialect: Dialect::Generic,
+        }
+    }
+
+    /// Sets every column of `pairs` to its value
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.set_all(&[("name", Value::Varchar("jeff")), ("age", Value::Int(3))]);
+    ///
+    /// assert_eq!(query.as_string(), "UPDATE users SET name = 'jeff', age = 3");
+    /// ```
+    pub fn set_all(&mut self, pairs: &[(&'a str, Value<'a>)]) {
+        for &(col, ref val) in pairs {
+            self.set.insert(col, val.clone());
+        }
+    }
+
+    /// Sets the column `col` to `NULL`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.set_null("nickname");
+    ///
+    /// assert_eq!(query.as_string(), "UPDATE users SET nickname = NULL");
+    /// assert_eq!(query.set["nickname"], Value::Null);
+    /// ```
+    pub fn set_null(&mut self, col: &'a str) {
+        self.set.insert(col, Value::Null);
+    }
+
+    /// Restricts the query to the named table itself, excluding tables inheriting from it
+    ///
+    /// This renders the `ONLY` keyword before the table, which is only supported by Postgres.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Dialect};
+    ///
+    /// let mut query = UpdateQuery::update("cities");
+    /// query.only();
+    /// query.dialect(Dialect::Postgres);
+    ///
+    /// assert_eq!(query.as_string(), "UPDATE ONLY cities");
+    /// ```
+    pub fn only(&mut self) {
+        self.only = true;
+    }
+
+    /// Restricts the query to the `partitions` of a partitioned table
+    ///
+    /// This renders a `PARTITION` clause after the table, which is only supported by MySQL.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Dialect};
+    ///
+    /// let mut query = UpdateQuery::update("logs");
+    /// query.partition(&["p0", "p1"]);
+    /// query.dialect(Dialect::MySql);
+    ///
+    /// assert_eq!(query.as_string(), "UPDATE logs PARTITION (p0, p1)");
+    /// ```
+    pub fn partition(&mut self, partitions: &[&'a str]) {
+        self.partitions = partitions.to_vec();
+    }
+
+    /// Set the limit of the Query to the value of `l`
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::{UpdateQuery, Value};
+    /// 
+    /// let mut query = UpdateQuery::update("users");
+    /// // set the limit
+    /// query.limit(12);
+    /// 
+    /// // assert that the limit is actually there
+    /// assert_eq!(query.get_limit(), Some(12))
+    /// ```
+    pub fn limit(&mut self, l: usize) {
+        self.limit = Some(l);
+    }
+
+    /// Returns whether or not the [`UpdateQuery`] has a limit
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::UpdateQuery;
+    ///  
+    /// // create the query
+    /// let mut query = UpdateQuery::update("cities");
+    /// 
+    /// assert_eq!(query.has_limit(), false);
+    /// 
+    /// // set limit
+    /// query.limit(1);
+    /// assert_eq!(query.has_limit(), true);
+    /// ```
+    /// [`UpdateQuery`]: ./struct.UpdateQuery.html
+    pub fn has_limit(&self) -> bool {
+        self.limit.is_some()
+    }
+    /// Returns the limit of the [`UpdateQuery`] if there is one
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::UpdateQuery;
+    /// // create the query
+    /// let mut query = UpdateQuery::update("countries");
+    /// 
+    /// assert_eq!(query.get_limit(), None);
+    /// 
+    /// // set the limit
+    /// query.limit(12);
+    /// assert_eq!(query.get_limit(), Some(12));
+    /// ```
+    /// [`UpdateQuery`]: ./struct.UpdateQuery.html
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Adds a [`OrderBy`] clause to the query, which decides the rows a limit applies to
+    ///
+    /// Ordered updates are only supported by MySQL.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, OrderBy, Value, Dialect};
+    ///
+    /// let mut query = UpdateQuery::update("tickets");
+    /// query.set.insert("assignee", Value::Varchar("anna"));
+    /// query.order_by(OrderBy::Row("created"));
+    /// query.limit(5);
+    /// query.dialect(Dialect::MySql);
+    ///
+    /// assert_eq!(query.as_string(), "UPDATE tickets SET assignee = 'anna' ORDER BY created LIMIT 5");
+    /// ```
+    ///
+    /// [`OrderBy`]: ./enum.OrderBy.html
+    pub fn order_by(&mut self, ob: OrderBy<'a>) {
+        self.order_by = Some(ob);
+    }
+
+    /// Restricts the query to rows where `column` equals `value`
+    ///
+    /// Unlike the clauses in `whre`, a scope can not be removed from the query again
+    /// and is always AND-combined with all other conditions. This makes it suitable
+    /// for mandatory filters such as tenant isolation.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value, WhereClause, Condition};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.scope("tenant_id", Value::Int(7));
+    /// query.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    /// query.whre.push_or(WhereClause::new("name", Value::Varchar("bert")));
+    ///
+    /// assert_eq!(query.as_string(), "UPDATE users WHERE tenant_id = 7 AND (name = 'anna' OR name = 'bert')");
+    /// ```
+    pub fn scope(&mut self, column: &'a str, value: Value<'a>) {
+        self.scope.push(WhereClause::new(column, value));
+    }
+
+    /// Works like [`SelectQuery::where_cond`]
+    ///
+    /// [`SelectQuery::where_cond`]: ./struct.SelectQuery.html#method.where_cond
+    pub fn where_cond(&mut self, cond: Cond<'a>) {
+        self.conds.push(cond);
+    }
+
+    /// Works like [`SelectQuery::and_where`]
+    ///
+    /// [`SelectQuery::and_where`]: ./struct.SelectQuery.html#method.and_where
+    pub fn and_where(&mut self, clause: WhereClause<'a>) {
+        self.whre.push(clause);
+    }
+
+    /// Works like [`SelectQuery::or_where`]
+    ///
+    /// [`SelectQuery::or_where`]: ./struct.SelectQuery.html#method.or_where
+    pub fn or_where(&mut self, clause: WhereClause<'a>) {
+        self.whre.push_or(clause);
+    }
+
+    /// Excludes soft-deleted rows, i.e. rows where `column` is not `NULL`
+    ///
+    /// Like a [`scope`] the filter can not be removed again and is AND-combined
+    /// with all other conditions.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value, WhereClause};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    /// query.exclude_deleted("deleted_at");
+    ///
+    /// assert_eq!(query.as_string(), "UPDATE users WHERE deleted_at IS NULL AND name = 'anna'");
+    /// ```
+    ///
+    /// [`scope`]: #method.scope
+    pub fn exclude_deleted(&mut self, column: &'a str) {
+        self.scope.push(WhereClause::with_operator(column, Operator::Is, Value::Null));
+    }
+
+    /// Sorts the [`WhereClause`]s of the query into a deterministic order
+    ///
+    /// Queries with the same conditions render to the same SQL after this, no matter
+    /// in which order the conditions were added. The clauses are only reordered if they
+    /// are all combined with the same [`Condition`], otherwise reordering could change
+    /// which rows match and the query is left as it is.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value, WhereClause};
+    ///
+    /// let mut a = UpdateQuery::update("users");
+    /// a.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    /// a.whre.push(WhereClause::new("age", Value::Int(30)));
+    ///
+    /// let mut b = UpdateQuery::update("users");
+    /// b.whre.push(WhereClause::new("age", Value::Int(30)));
+    /// b.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+    ///
+    /// a.canonicalize();
+    /// b.canonicalize();
+    /// assert_eq!(a.as_string(), b.as_string());
+    /// ```
+    ///
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    /// [`Condition`]: ./enum.Condition.html
+    pub fn canonicalize(&mut self) {
+        canonicalize(&mut self.whre);
+    }
+
+    /// Renders the query against the table `new` instead of `old`
+    ///
+    /// This replaces `old` wherever it is used as a table, as well as in every column
+    /// qualified with it (`old.column`), including column references in values and
+    /// subqueries. Raw SQL fragments like [`JoinCondition::On`] are left untouched.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value, WhereClause};
+    ///
+    /// let mut q = UpdateQuery::update("users");
+    /// q.set.insert("name", Value::Varchar("anna"));
+    /// q.whre.push(WhereClause::new("users.id", Value::Int(3)));
+    /// q.rename_table("users", "tmp_users");
+    ///
+    /// assert_eq!(q.as_string(), "UPDATE tmp_users SET name = 'anna' WHERE tmp_users.id = 3");
+    /// ```
+    ///
+    /// [`JoinCondition::On`]: ./enum.JoinCondition.html#variant.On
+    pub fn rename_table(&mut self, old: &'a str, new: &'a str) {
+        self.renames.push((old, new));
+    }
+
+    /// Returns the [`String`] representation of the [`UpdateQuery`]
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use query_builder::{UpdateQuery, Value};
+    /// 
+    /// let mut query = UpdateQuery::update("users");
+    /// 
+    /// query.set.insert("name", Value::Varchar("jeff")); 
+    /// query.limit(1);
+    /// 
+    /// assert_eq!(query.as_string(),"UPDATE users SET name = 'jeff' LIMIT 1");
+    /// ```
+    /// [`UpdateQuery`]: ./struct.UpateQuery.html
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn as_string(&self) -> String {
+        let mut res = String::new();
+        /* writing into a String never fails */
+        self.write_to(&mut res).unwrap();
+        res
+    }
+
+    /// Writes the SQL of [`as_string`] into `w` instead of returning a new [`String`]
+    ///
+    /// This allows reusing one buffer for rendering many queries.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::UpdateQuery;
+    ///
+    /// let query = UpdateQuery::update("users");
+    ///
+    /// let mut buf = String::new();
+    /// query.write_to(&mut buf).unwrap();
+    /// assert_eq!(buf, query.as_string());
+    /// ```
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn write_to<W: Write>(&self, w: &mut W) -> FormatResult {
+        w.write_str(&self.render(&mut Context::literal(self.dialect).rendered_by(self.renderer)))
+    }
+
+    /// Returns the query with a placeholder for every value, along with
+    /// the values in the order of their placeholders
+    ///
+    /// Placeholders are numbered (`$1`, `$2`, ...) for [`Dialect::Postgres`] and
+    /// `?` for all other dialects. Column references and intervals are part of
+    /// the statement and are never replaced.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value, WhereClause};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.set.insert("name", Value::Varchar("jeff"));
+    /// query.whre.push(WhereClause::new("age", Value::Int(30)));
+    ///
+    /// let (sql, values) = query.as_parameterized();
+    /// assert_eq!(sql, "UPDATE users SET name = ? WHERE age = ?");
+    /// assert_eq!(values, vec![Value::Varchar("jeff"), Value::Int(30)]);
+    /// ```
+    ///
+    /// [`Dialect::Postgres`]: ./enum.Dialect.html#variant.Postgres
+    pub fn as_parameterized(&self) -> (String, Vec<Value<'a>>) {
+        self.as_parameterized_from(1)
+    }
+
+    /// Works like [`as_parameterized`], but numbers the placeholders from `start_index` on
+    ///
+    /// See [`SelectQuery::as_parameterized_from`].
+    ///
+    /// [`as_parameterized`]: #method.as_parameterized
+    /// [`SelectQuery::as_parameterized_from`]: ./struct.SelectQuery.html#method.as_parameterized_from
+    pub fn as_parameterized_from(&self, start_index: usize) -> (String, Vec<Value<'a>>) {
+        let mut ctx = Context::placeholders(self.dialect).starting_at(start_index);
+        let sql = self.render(&mut ctx);
+
+        (sql, ctx.into_values())
+    }
+
+    /// Returns the query with all values written out, for logging only
+    ///
+    /// Works like [`SelectQuery::as_debug_string`].
+    ///
+    /// [`SelectQuery::as_debug_string`]: ./struct.SelectQuery.html#method.as_debug_string
+    pub fn as_debug_string(&self) -> String {
+        self.render(&mut Context::escaped(self.dialect).rendered_by(self.renderer))
+    }
+
+    /// Returns the query like [`as_string`] along with [`Warning`]s about likely mistakes
+    ///
+    /// Works like [`SelectQuery::render_with_warnings`].
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`Warning`]: ./enum.Warning.html
+    /// [`SelectQuery::render_with_warnings`]: ./struct.SelectQuery.html#method.render_with_warnings
+    pub fn render_with_warnings(&self) -> (String, Vec<Warning>) {
+        let mut warnings = Vec::new();
+        if self.scope.is_empty() && self.whre.is_empty() && self.conds.is_empty() {
+            warnings.push(Warning::NoWhere);
+        }
+        if self.limit.is_some() && self.order_by.is_none() {
+            warnings.push(Warning::UnorderedLimit);
+        }
+        (self.as_string(), warnings)
+    }
+
+    /// Returns the query with a placeholder for every value, along with the values
+    ///
+    /// Works like [`SelectQuery::into_prepared`].
+    ///
+    /// [`SelectQuery::into_prepared`]: ./struct.SelectQuery.html#method.into_prepared
+    pub fn into_prepared(mut self) -> (String, Vec<Value<'a>>) {
+        self.canonicalize();
+        self.as_parameterized()
+    }
+
+    /// Returns only the WHERE clause of the query with placeholders, along with
+    /// the values in the order of their placeholders
+    ///
+    /// This is meant for code that builds the rest of the statement itself. The
+    /// clause includes the [`scope`] conditions, and is empty if there are no conditions.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value, WhereClause};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.whre.push(WhereClause::new("age", Value::Int(30)));
+    /// query.whre.push(WhereClause::new("city", Value::Varchar("berlin")));
+    ///
+    /// let (sql, values) = query.where_parameterized();
+    /// assert_eq!(sql, "WHERE age = ? AND city = ?");
+    /// assert_eq!(values, vec![Value::Int(30), Value::Varchar("berlin")]);
+    /// ```
+    ///
+    /// [`scope`]: #method.scope
+    pub fn where_parameterized(&self) -> (String, Vec<Value<'a>>) {
+        let mut ctx = Context::placeholders(self.dialect);
+        let sql = Clauses {
+            scope: &self.scope,
+            whre: &self.whre.entries,
+            conds: &self.conds,
+            group_by: None,
+            having: &[],
+            having_raw: &[],
+            order_by: None,
+            limit: None,
+            offset: None,
+            bind_limit: false,
+            max_conditions: self.max_conditions,
+        }.where_clause(&mut ctx).unwrap_or_default();
+
+        (sql, ctx.into_values())
+    }
+
+    /// Returns the values that [`as_parameterized`] binds, each paired with the
+    /// column it is compared with or assigned to
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value, WhereClause};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.set.insert("name", Value::Varchar("jeff"));
+    /// query.whre.push(WhereClause::new("age", Value::Int(30)));
+    ///
+    /// assert_eq!(query.parameter_manifest(), vec![
+    ///     ("name".to_string(), Value::Varchar("jeff")),
+    ///     ("age".to_string(), Value::Int(30)),
+    /// ]);
+    /// ```
+    ///
+    /// [`as_parameterized`]: #method.as_parameterized
+    pub fn parameter_manifest(&self) -> Vec<(String, Value<'a>)> {
+        let mut ctx = Context::placeholders(self.dialect);
+        self.render(&mut ctx);
+
+        ctx.bound
+    }
+
+    /// Returns every value written into the query, in the order they appear in it
+    ///
+    /// This includes the values of subqueries and does not depend on whether the
+    /// query is parameterized, which makes it useful for auditing. Lists are replaced
+    /// by their items, column references are left out as they are not values.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value, WhereClause};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.set.insert("name", Value::Varchar("anna"));
+    /// query.whre.push(WhereClause::new("id", Value::Int(3)));
+    ///
+    /// assert_eq!(query.collect_values(), vec![&Value::Varchar("anna"), &Value::Int(3)]);
+    /// ```
+    pub fn collect_values(&self) -> Vec<&Value<'a>> {
+        let mut values = Vec::new();
+        for v in self.set.values() {
+            collect_value(v, &mut values);
+        }
+        collect_clauses(&self.scope, &mut values);
+        collect_clauses(self.whre.clauses(), &mut values);
+        for c in &self.conds {
+            collect_conds(c, &mut values);
+        }
+        values
+    }
+
+    /// Sets the [`Dialect`] the query is rendered for
+    ///
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn dialect(&mut self, dialect: Dialect) {
+        self.dialect = dialect;
+    }
+
+    /// Sets the [`ValueRenderer`] used for the values written into the query,
+    /// instead of the one of its [`Dialect`]
+    ///
+    /// [`ValueRenderer`]: ./trait.ValueRenderer.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn renderer(&mut self, renderer: &'a dyn ValueRenderer) {
+        self.renderer = Some(renderer);
+    }
+
+    /// Sets whether the keywords of the query are written in upper or lower case
+    ///
+    /// [`KeywordCase::Upper`] is used unless told otherwise.
+    ///
+    /// [`KeywordCase::Upper`]: ./enum.KeywordCase.html#variant.Upper
+    pub fn keyword_case(&mut self, case: KeywordCase) {
+        self.keyword_case = case;
+    }
+
+    /// Limits the query to at most `max` conditions, as a guard against accidentally huge queries
+    ///
+    /// Every [`WhereClause`] counts as one condition, except for `IN` lists, which count
+    /// every value. [`try_as_string`] and [`validate`] fail if there are more.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value, WhereClause, Operator, QueryError};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.max_conditions(2);
+    /// query.whre.push(WhereClause::with_operator("id", Operator::In, Value::List(vec![Value::Int(1), Value::Int(2), Value::Int(3)])));
+    ///
+    /// assert_eq!(query.validate(), Err(QueryError::TooManyConditions { count: 3, max: 2 }));
+    /// ```
+    ///
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    /// [`try_as_string`]: #method.try_as_string
+    /// [`validate`]: #method.validate
+    pub fn max_conditions(&mut self, max: usize) {
+        self.max_conditions = Some(max);
+    }
+
+    /// Returns the [`String`] representation of the [`UpdateQuery`], or an error if
+    /// the query uses features its [`Dialect`] does not support
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value, Dialect};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.set.insert("tags", Value::Array(vec![Value::Int(1)]));
+    /// assert!(query.try_as_string().is_err());
+    ///
+    /// query.dialect(Dialect::Postgres);
+    /// assert!(query.try_as_string().is_ok());
+    /// ```
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`UpdateQuery`]: ./struct.UpdateQuery.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn try_as_string(&self) -> Result<String, QueryError> {
+        let mut ctx = Context::literal(self.dialect).rendered_by(self.renderer);
+        let sql = self.render(&mut ctx);
+
+        ctx.finish(sql)
+    }
+
+    /// Checks whether the [`UpdateQuery`] can be rendered for its [`Dialect`]
+    ///
+    /// [`UpdateQuery`]: ./struct.UpdateQuery.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn validate(&self) -> Result<(), QueryError> {
+        self.try_as_string().map(|_| ())
+    }
+
+    /// Returns only the `SET` clause of the query, e.g. to build the rest of the statement yourself
+    ///
+    /// The assignments are rendered exactly like in [`as_string`], an empty `set`
+    /// returns an empty [`String`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// assert_eq!(query.set_as_string(), "");
+    ///
+    /// query.set.insert("name", Value::Varchar("jeff"));
+    /// query.set.insert("age", Value::Int(30));
+    /// assert_eq!(query.set_as_string(), "SET name = 'jeff', age = 30");
+    /// ```
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn set_as_string(&self) -> String {
+        let mut ctx = Context::literal(self.dialect).rendered_by(self.renderer);
+        ctx.choose_case(self.keyword_case);
+        self.render_set(&mut ctx)
+    }
+
+    /// Checks that no column is set more than once when ignoring case
+    ///
+    /// Most databases treat `Name` and `name` as the same column and reject a statement
+    /// that uses both. This check is not part of [`validate`], as some databases do
+    /// have case sensitive column names.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value, QueryError};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.set.insert("Name", Value::Varchar("anna"));
+    /// assert!(query.check_duplicate_columns().is_ok());
+    ///
+    /// query.set.insert("name", Value::Varchar("bert"));
+    /// assert_eq!(
+    ///     query.check_duplicate_columns(),
+    ///     Err(QueryError::DuplicateColumn { column: "name".to_string() })
+    /// );
+    /// ```
+    ///
+    /// [`validate`]: #method.validate
+    pub fn check_duplicate_columns(&self) -> Result<(), QueryError> {
+        check_duplicate_columns(self.set.keys().cloned())
+    }
+
+    /// Declares that `column` holds at most `max_length` characters, like a `VARCHAR(max_length)`
+    ///
+    /// Text values that are longer make [`try_as_string`] and [`validate`] fail, instead
+    /// of the database rejecting the statement later on.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value, QueryError};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.max_length("name", 4);
+    ///
+    /// query.set.insert("name", Value::Varchar("anna"));
+    /// assert_eq!(query.try_as_string(), Ok("UPDATE users SET name = 'anna'".to_string()));
+    ///
+    /// query.set.insert("name", Value::Varchar("annabel"));
+    /// assert_eq!(
+    ///     query.validate(),
+    ///     Err(QueryError::TooLong { column: "name".to_string(), max_length: 4 })
+    /// );
+    /// ```
+    ///
+    /// [`try_as_string`]: #method.try_as_string
+    /// [`validate`]: #method.validate
+    pub fn max_length(&mut self, column: &'a str, max_length: usize) {
+        self.max_lengths.insert(column, max_length);
+    }
+
+    /// Resets `column` to its default value, this is a shorthand for setting it to [`Value::Default`]
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.set_default("avatar");
+    /// query.set.insert("name", Value::Varchar("anna"));
+    ///
+    /// assert_eq!(query.as_string(), "UPDATE users SET avatar = DEFAULT, name = 'anna'");
+    /// ```
+    ///
+    /// [`Value::Default`]: ./enum.Value.html#variant.Default
+    pub fn set_default(&mut self, column: &'a str) {
+        self.set.insert(column, Value::Default);
+    }
+
+    /// Adds the assignments and the [`WhereClause`]s of `other` to this query
+    ///
+    /// `other` has to update the same table. A column may be set by both queries as long
+    /// as it gets the same value, otherwise the queries conflict. The query is left
+    /// unchanged if an error is returned.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{UpdateQuery, Value, WhereClause, QueryError};
+    ///
+    /// let mut query = UpdateQuery::update("users");
+    /// query.set.insert("name", Value::Varchar("anna"));
+    /// query.whre.push(WhereClause::new("id", Value::Int(3)));
+    ///
+    /// let mut other = UpdateQuery::update("users");
+    /// other.set.insert("age", Value::Int(31));
+    /// query.merge(other).unwrap();
+    ///
+    /// assert_eq!(query.as_string(), "UPDATE users SET name = 'anna', age = 31 WHERE id = 3");
+    ///
+    /// let mut other = UpdateQuery::update("users");
+    /// other.set.insert("name", Value::Varchar("bert"));
+    /// assert_eq!(query.merge(other), Err(QueryError::ConflictingAssignment { column: "name".to_string() }));
+    /// ```
+    ///
+    /// [`WhereClause`]: ./struct.WhereClause.html
+    pub fn merge(&mut self, other: UpdateQuery<'a>) -> Result<(), QueryError> {
+        if self.update != other.update {
+            return Err(QueryError::DifferentTables {
+                left: self.update.to_string(),
+                right: other.update.to_string(),
+            });
+        }
+        for (column, value) in other.set.iter() {
+            if matches!(self.set.get(column), Some(v) if v != value) {
+                return Err(QueryError::ConflictingAssignment { column: column.to_string() });
+            }
+        }
+
+        for (column, value) in other.set.entries {
+            self.set.insert(column, value);
+        }
+        self.max_lengths.extend(other.max_lengths);
+        self.whre.extend(other.whre);
+        self.scope.extend(other.scope);
+        self.conds.extend(other.conds);
+        Ok(())
+    }
+
+    /// Renders the query, passing all values through `ctx`
+    fn render(&self, ctx: &mut Context<'a>) -> String {
+        ctx.choose_case(self.keyword_case);
+        let renamed = ctx.push_renames(&self.renames);
+        let mut res = ctx.case().format("UPDATE {}{}", &[&only(self.only, ctx), &ctx.ident(self.update)]);
+        res.push_str(&partition(&self.partitions, ctx));
+
+        let set = self.render_set(ctx);
+        if !set.is_empty() {
+            res.push(' ');
+            res.push_str(&set);
+        }
+
+        if self.order_by.is_some() {
+            ctx.require("ordered updates", &[Dialect::MySql]);
+        }
+
+        Clauses {
+            scope: &self.scope,
+            whre: &self.whre.entries,
+            conds: &self.conds,
+            group_by: None,
+            having: &[],
+            having_raw: &[],
+            order_by: self.order_by.as_ref(),
+            limit: self.limit,
+            offset: None,
+            bind_limit: false,
+            max_conditions: self.max_conditions,
+        }.append_to(&mut res, ctx);
+
+        ctx.renames.truncate(renamed);
+        res
+    }
+
+    /// Renders the `SET` clause with all assignments, or nothing if there are none
+    fn render_set(&self, ctx: &mut Context<'a>) -> String {
+        let mut res = String::new();
+        for (i, (k, v)) in self.set.iter().enumerate() {
+            res.push_str(&ctx.kw(if i == 0 { "SET " } else { ", " }));
+            res.push_str(k);
+            res.push_str(" = ");
+            if let Some(&max) = self.max_lengths.get(k) {
+                ctx.check_length(k, v, max);
+            }
+            res.push_str(&ctx.value(k, v));
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Struct representing an SQL MERGE statement, the portable way of inserting or updating rows
+///
+/// Rows of the source that match a row of the target according to the [`on`] condition
+/// update it with the values of [`update`], all others are inserted with the values of
+/// [`insert`]. Either action is left out if it has no values.
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{MergeQuery, Value, Dialect};
+///
+/// let mut query = MergeQuery::into("users").using("new_users AS s").on("users.id = s.id");
+/// query.update.insert("name", Value::Column("s.name"));
+/// query.insert.insert("id", Value::Column("s.id"));
+/// query.insert.insert("name", Value::Column("s.name"));
+/// query.dialect(Dialect::Postgres);
+///
+/// assert_eq!(
+///     query.as_string(),
+///     "MERGE INTO users USING new_users AS s ON users.id = s.id \
+///      WHEN MATCHED THEN UPDATE SET name = s.name \
+///      WHEN NOT MATCHED THEN INSERT (id, name) VALUES (s.id, s.name)"
+/// );
+/// ```
+///
+/// [`on`]: #method.on
+/// [`update`]: #structfield.update
+/// [`insert`]: #structfield.insert
+pub struct MergeQuery<'a> {
+    into: &'a str,
+    using: Option<FromItem<'a>>,
+    on: Option<&'a str>,
+    /// The columns to set, with their values, when a source row matches a target row
+    pub update: BTreeMap<&'a str, Value<'a>>,
+    /// The columns to insert, with their values, when a source row matches no target row
+    pub insert: BTreeMap<&'a str, Value<'a>>,
+    keyword_case: KeywordCase,
+    renderer: Option<&'a dyn ValueRenderer>,
+    dialect: Dialect,
+}
+
+impl<'a> Display for MergeQuery<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+impl<'a> MergeQuery<'a> {
+    /// Returns a new [`MergeQuery`] that merges rows into the table `table`
+    ///
+    /// [`MergeQuery`]: ./struct.MergeQuery.html
+    pub fn into(table: &'a str) -> MergeQuery<'a> {
+        MergeQuery {
+            into: table,
+            using: None,
+            on: None,
+            update: BTreeMap::new(),
+            insert: BTreeMap::new(),
+            keyword_case: KeywordCase::Upper,
+            renderer: None,
+            dialect: Dialect::Generic,
+        }
+    }
+
+    /// Sets the table the rows to merge come from
+    ///
+    /// The table may carry an alias, e.g. `"new_users AS s"`.
+    pub fn using(mut self, table: &'a str) -> Self {
+        self.using = Some(FromItem::Table(table));
+        self
+    }
+
+    /// Sets the rows to merge to the result of the [`SelectQuery`] `q`
+    ///
+    /// The subquery is referred to by its [`alias`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{MergeQuery, SelectQuery, Value};
+    ///
+    /// let staged = SelectQuery::select(&["id", "name"]).from("staging").alias("s");
+    /// let mut query = MergeQuery::into("users").using_subquery(staged).on("users.id = s.id");
+    /// query.update.insert("name", Value::Column("s.name"));
+    ///
+    /// assert_eq!(
+    ///     query.as_string(),
+    ///     "MERGE INTO users USING (SELECT id, name FROM staging) AS s ON users.id = s.id WHEN MATCHED THEN UPDATE SET name = s.name"
+    /// );
+    /// ```
+    ///
+    /// [`SelectQuery`]: ./struct.SelectQuery.html
+    /// [`alias`]: ./struct.SelectQuery.html#method.alias
+    pub fn using_subquery(mut self, q: SelectQuery<'a>) -> Self {
+        self.using = Some(FromItem::Subquery(Box::new(q)));
+        self
+    }
+
+    /// Sets the condition deciding which source rows match which target rows
+    pub fn on(mut self, condition: &'a str) -> Self {
+        self.on = Some(condition);
+        self
+    }
+
+    /// Returns the [`String`] representation of the [`MergeQuery`]
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`MergeQuery`]: ./struct.MergeQuery.html
+    pub fn as_string(&self) -> String {
+        let mut res = String::new();
+        /* writing into a String never fails */
+        self.write_to(&mut res).unwrap();
+        res
+    }
+
+    /// Writes the SQL of [`as_string`] into `w` instead of returning a new [`String`]
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn write_to<W: Write>(&self, w: &mut W) -> FormatResult {
+        w.write_str(&self.render(&mut Context::literal(self.dialect).rendered_by(self.renderer)))
+    }
+
+    /// Returns the query with a placeholder for every value, along with
+    /// the values in the order of their placeholders
+    ///
+    /// Works like [`SelectQuery::as_parameterized`].
+    ///
+    /// [`SelectQuery::as_parameterized`]: ./struct.SelectQuery.html#method.as_parameterized
+    pub fn as_parameterized(&self) -> (String, Vec<Value<'a>>) {
+        let mut ctx = Context::placeholders(self.dialect);
+        let sql = self.render(&mut ctx);
+
+        (sql, ctx.into_values())
+    }
+
+    /// Returns the query with a placeholder for every value, along with the values
+    ///
+    /// Works like [`SelectQuery::into_prepared`].
+    ///
+    /// [`SelectQuery::into_prepared`]: ./struct.SelectQuery.html#method.into_prepared
+    pub fn into_prepared(self) -> (String, Vec<Value<'a>>) {
+        self.as_parameterized()
+    }
+
+    /// Returns every value written into the query, in the order they appear in it
+    ///
+    /// Works like [`SelectQuery::collect_values`].
+    ///
+    /// [`SelectQuery::collect_values`]: ./struct.SelectQuery.html#method.collect_values
+    pub fn collect_values(&self) -> Vec<&Value<'a>> {
+        let mut values = Vec::new();
+        if let Some(FromItem::Subquery(ref q)) = self.using {
+            values.extend(q.collect_values());
+        }
+        for v in self.update.values().chain(self.insert.values()) {
+            collect_value(v, &mut values);
+        }
+        values
+    }
+
+    /// Sets the [`Dialect`] the query is rendered for
+    ///
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn dialect(&mut self, dialect: Dialect) {
+        self.dialect = dialect;
+    }
+
+    /// Sets the [`ValueRenderer`] used for the values written into the query,
+    /// instead of the one of its [`Dialect`]
+    ///
+    /// [`ValueRenderer`]: ./trait.ValueRenderer.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn renderer(&mut self, renderer: &'a dyn ValueRenderer) {
+        self.renderer = Some(renderer);
+    }
+
+    /// Sets whether the keywords of the query are written in upper or lower case
+    ///
+    /// [`KeywordCase::Upper`] is used unless told otherwise.
+    ///
+    /// [`KeywordCase::Upper`]: ./enum.KeywordCase.html#variant.Upper
+    pub fn keyword_case(&mut self, case: KeywordCase) {
+        self.keyword_case = case;
+    }
+
+    /// Returns the [`String`] representation of the [`MergeQuery`], or an error if
+    /// the query uses features its [`Dialect`] does not support
+    ///
+    /// MERGE statements are supported by Postgres and SQL Server.
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`MergeQuery`]: ./struct.MergeQuery.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn try_as_string(&self) -> Result<String, QueryError> {
+        let mut ctx = Context::literal(self.dialect).rendered_by(self.renderer);
+        let sql = self.render(&mut ctx);
+
+        ctx.finish(sql)
+    }
+
+    /// Checks whether the [`MergeQuery`] can be rendered for its [`Dialect`]
+    ///
+    /// [`MergeQuery`]: ./struct.MergeQuery.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn validate(&self) -> Result<(), QueryError> {
+        self.try_as_string().map(|_| ())
+    }
+
+    /// Returns the query like [`as_string`] along with [`Warning`]s about likely mistakes
+    ///
+    /// The rows a merge touches are picked by its `ON` condition, so only the warnings
+    /// of a subquery given to [`using_subquery`] are reported.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{MergeQuery, SelectQuery, Warning};
+    ///
+    /// let mut source = SelectQuery::select(&["*"]).from("staged");
+    /// source.limit(100);
+    /// let query = MergeQuery::into("users").using_subquery(source.alias("s")).on("users.id = s.id");
+    ///
+    /// assert_eq!(query.render_with_warnings().1, vec![Warning::UnorderedLimit, Warning::SelectStar]);
+    /// ```
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`Warning`]: ./enum.Warning.html
+    /// [`using_subquery`]: #method.using_subquery
+    pub fn render_with_warnings(&self) -> (String, Vec<Warning>) {
+        let warnings = match self.using {
+            Some(FromItem::Subquery(ref q)) => q.render_with_warnings().1,
+            _ => Vec::new(),
+        };
+        (self.as_string(), warnings)
+    }
+
+    /// Renders the query, passing all values through `ctx`
+    fn render(&self, ctx: &mut Context<'a>) -> String {
+        ctx.choose_case(self.keyword_case);
+        ctx.require("MERGE statements", &[Dialect::Postgres, Dialect::SqlServer]);
+        let mut res = ctx.case().format("MERGE INTO {}", &[&self.into]);
+
+        if let Some(ref u) = self.using {
+            res.push_str(&ctx.kw(" USING "));
+            res.push_str(&u.render(ctx));
+        }
+
+        if let Some(on) = self.on {
+            res.push_str(&ctx.kw(" ON "));
+            res.push_str(on);
+        }
+
+        for (i, (k, v)) in self.update.iter().enumerate() {
+            res.push_str(&ctx.kw(if i == 0 { " WHEN MATCHED THEN UPDATE SET " } else { ", " }));
+            res.push_str(k);
+            res.push_str(" = ");
+            res.push_str(&ctx.value(k, v));
+        }
+
+        if !self.insert.is_empty() {
+            let columns: Vec<&str> = self.insert.keys().cloned().collect();
+            let values: Vec<String> = self.insert.iter().map(|(k, v)| ctx.value(k, v)).collect();
+
+            res.push_str(&ctx.case().format(
+                " WHEN NOT MATCHED THEN INSERT ({}) VALUES ({})",
+                &[&columns.join(", "), &values.join(", ")]
+            ));
+        }
+
+        res
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the types of the columns of a [`CreateTableQuery`]
+///
+/// Types that take a size modifier only render it if it is set.
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::ColumnType;
+///
+/// assert_eq!(ColumnType::Varchar(Some(255)).to_string(), "VARCHAR(255)");
+/// assert_eq!(ColumnType::Decimal { precision: Some(10), scale: Some(2) }.to_string(), "DECIMAL(10, 2)");
+/// assert_eq!(ColumnType::Decimal { precision: None, scale: None }.to_string(), "DECIMAL");
+/// ```
+///
+/// [`CreateTableQuery`]: ./struct.CreateTableQuery.html
+pub enum ColumnType {
+    Bool,
+    Smallint,
+    Int,
+    Bigint,
+    Text,
+    Date,
+    Timestamp,
+    /// Variable length string with an optional maximum length
+    Varchar(Option<u32>),
+    /// Fixed length string with an optional length
+    Char(Option<u32>),
+    /// Exact number with an optional total number of digits and digits after the decimal point
+    ///
+    /// The scale is ignored if no precision is given.
+    Decimal {
+        precision: Option<u8>,
+        scale: Option<u8>,
+    },
+}
+
+impl Display for ColumnType {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            ColumnType::Bool                => write!(f, "BOOLEAN"),
+            ColumnType::Smallint            => write!(f, "SMALLINT"),
+            ColumnType::Int                 => write!(f, "INT"),
+            ColumnType::Bigint              => write!(f, "BIGINT"),
+            ColumnType::Text                => write!(f, "TEXT"),
+            ColumnType::Date                => write!(f, "DATE"),
+            ColumnType::Timestamp           => write!(f, "TIMESTAMP"),
+            ColumnType::Varchar(None)       => write!(f, "VARCHAR"),
+            ColumnType::Varchar(Some(l))    => write!(f, "VARCHAR({})", l),
+            ColumnType::Char(None)          => write!(f, "CHAR"),
+            ColumnType::Char(Some(l))       => write!(f, "CHAR({})", l),
+            ColumnType::Decimal { precision: None, .. } => write!(f, "DECIMAL"),
+            ColumnType::Decimal { precision: Some(p), scale: None } => write!(f, "DECIMAL({})", p),
+            ColumnType::Decimal { precision: Some(p), scale: Some(s) } => write!(f, "DECIMAL({}, {})", p, s),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing whether a column may hold NULL
+pub enum Nullability {
+    Nullable,
+    NotNull,
+}
+
+impl Display for Nullability {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            Nullability::Nullable   => write!(f, "NULL"),
+            Nullability::NotNull    => write!(f, "NOT NULL"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Struct representing an SQL CREATE TABLE statement
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{CreateTableQuery, ColumnType};
+///
+/// let mut query = CreateTableQuery::create("users");
+/// query.column("id", ColumnType::Int);
+/// query.column("name", ColumnType::Varchar(Some(255)));
+///
+/// assert_eq!(query.as_string(), "CREATE TABLE users (id INT, name VARCHAR(255))");
+/// ```
+pub struct CreateTableQuery<'a> {
+    table: &'a str,
+    columns: Vec<(&'a str, ColumnType)>,
+    unique: Vec<(Option<&'a str>, &'a [&'a str])>,
+}
+
+impl<'a> Display for CreateTableQuery<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+impl<'a> CreateTableQuery<'a> {
+    /// Returns a new [`CreateTableQuery`] that creates the table `table`
+    ///
+    /// [`CreateTableQuery`]: ./struct.CreateTableQuery.html
+    pub fn create(table: &'a str) -> CreateTableQuery<'a> {
+        CreateTableQuery {
+            table,
+            columns: Vec::new(),
+            unique: Vec::new(),
+        }
+    }
+
+    /// Adds the column `name` of type `ty` to the table
+    ///
+    /// Columns are created in the order they were added.
+    pub fn column(&mut self, name: &'a str, ty: ColumnType) {
+        self.columns.push((name, ty));
+    }
+
+    /// Adds a constraint that no two rows have the same values in all of `columns`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{CreateTableQuery, ColumnType};
+    ///
+    /// let mut query = CreateTableQuery::create("members");
+    /// query.column("team", ColumnType::Int);
+    /// query.column("user", ColumnType::Int);
+    /// query.unique(&["team", "user"]);
+    ///
+    /// assert_eq!(query.as_string(), "CREATE TABLE members (team INT, user INT, UNIQUE (team, user))");
+    /// ```
+    pub fn unique(&mut self, columns: &'a [&'a str]) {
+        self.unique.push((None, columns));
+    }
+
+    /// Adds a unique constraint over `columns` like [`unique`], giving it the name `name`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use query_builder::{CreateTableQuery, ColumnType};
+    ///
+    /// let mut query = CreateTableQuery::create("users");
+    /// query.column("email", ColumnType::Text);
+    /// query.unique_named("users_email_key", &["email"]);
+    ///
+    /// assert_eq!(query.as_string(), "CREATE TABLE users (email TEXT, CONSTRAINT users_email_key UNIQUE (email))");
+    /// ```
+    ///
+    /// [`unique`]: #method.unique
+    pub fn unique_named(&mut self, name: &'a str, columns: &'a [&'a str]) {
+        self.unique.push((Some(name), columns));
+    }
+
+    /// Returns the [`String`] representation of the [`CreateTableQuery`]
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`CreateTableQuery`]: ./struct.CreateTableQuery.html
+    pub fn as_string(&self) -> String {
+        let mut res = String::new();
+        /* writing into a String never fails */
+        self.write_to(&mut res).unwrap();
+        res
+    }
+
+    /// Writes the SQL of [`as_string`] into `w` instead of returning a new [`String`]
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn write_to<W: Write>(&self, w: &mut W) -> FormatResult {
+        write!(w, "CREATE TABLE {} (", self.table)?;
+        for (i, &(name, ty)) in self.columns.iter().enumerate() {
+            if i > 0 {
+                w.write_str(", ")?;
+            }
+            write!(w, "{} {}", name, ty)?;
+        }
+        for &(name, columns) in &self.unique {
+            w.write_str(", ")?;
+            if let Some(n) = name {
+                write!(w, "CONSTRAINT {} ", n)?;
+            }
+            write!(w, "UNIQUE ({})", columns.join(", "))?;
+        }
+        w.write_str(")")
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Struct representing a list of rows written out with the VALUES constructor
+///
+/// Besides being a statement of its own, it can be used as an inline table in the
+/// FROM clause of a [`SelectQuery`], e.g. for lookup tables or for joining with a
+/// list of values.
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{ValuesQuery, Value};
+///
+/// let mut query = ValuesQuery::new();
+/// query.row(vec![Value::Int(1), Value::Varchar("a")]);
+/// query.row(vec![Value::Int(2), Value::Varchar("b")]);
+///
+/// assert_eq!(query.as_string(), "VALUES (1, 'a'), (2, 'b')");
+/// ```
+///
+/// [`SelectQuery`]: ./struct.SelectQuery.html
+pub struct ValuesQuery<'a> {
+    rows: Vec<Vec<Value<'a>>>,
+    alias: Option<(&'a str, &'a [&'a str])>,
+}
+
+impl<'a> Display for ValuesQuery<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+impl<'a> Default for ValuesQuery<'a> {
+    fn default() -> Self {
+        ValuesQuery::new()
+    }
+}
+
+impl<'a> ValuesQuery<'a> {
+    /// Returns a new [`ValuesQuery`] without any rows
+    ///
+    /// [`ValuesQuery`]: ./struct.ValuesQuery.html
+    pub fn new() -> ValuesQuery<'a> {
+        ValuesQuery {
+            rows: Vec::new(),
+            alias: None,
+        }
+    }
+
+    /// Adds a row consisting of `values`
+    pub fn row(&mut self, values: Vec<Value<'a>>) {
+        self.rows.push(values);
+    }
+
+    /// Sets the name of the table and of its `columns` when the rows are used in a FROM clause
+    ///
+    /// The alias is ignored when the query is rendered on its own.
+    pub fn alias(mut self, alias: &'a str, columns: &'a [&'a str]) -> Self {
+        self.alias = Some((alias, columns));
+        self
+    }
+
+    /// Returns the [`String`] representation of the [`ValuesQuery`]
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`ValuesQuery`]: ./struct.ValuesQuery.html
+    pub fn as_string(&self) -> String {
+        self.render(&mut Context::literal(Dialect::Generic))
+    }
+
+    /// Returns the query with a placeholder for every value, along with
+    /// the values in the order of their placeholders
+    ///
+    /// Works like [`SelectQuery::as_parameterized`].
+    ///
+    /// [`SelectQuery::as_parameterized`]: ./struct.SelectQuery.html#method.as_parameterized
+    pub fn as_parameterized(&self) -> (String, Vec<Value<'a>>) {
+        let mut ctx = Context::placeholders(Dialect::Generic);
+        let sql = self.render(&mut ctx);
+
+        (sql, ctx.into_values())
+    }
+
+    /// Returns the query with a placeholder for every value, along with the values
+    ///
+    /// Works like [`SelectQuery::into_prepared`].
+    ///
+    /// [`SelectQuery::into_prepared`]: ./struct.SelectQuery.html#method.into_prepared
+    pub fn into_prepared(self) -> (String, Vec<Value<'a>>) {
+        self.as_parameterized()
+    }
+
+    /// Returns every value written into the query, in the order they appear in it
+    ///
+    /// Works like [`SelectQuery::collect_values`].
+    ///
+    /// [`SelectQuery::collect_values`]: ./struct.SelectQuery.html#method.collect_values
+    pub fn collect_values(&self) -> Vec<&Value<'a>> {
+        let mut values = Vec::new();
+        for v in self.rows.iter().flat_map(|r| r.iter()) {
+            collect_value(v, &mut values);
+        }
+        values
+    }
+
+    /// Renders the rows, passing all values through `ctx`
+    fn render(&self, ctx: &mut Context<'a>) -> String {
+        let mut res = ctx.kw("VALUES ");
+
+        for (i, row) in self.rows.iter().enumerate() {
+            if i > 0 {
+                res.push_str(", ");
+            }
+            let values: Vec<String> = row.iter().map(|v| ctx.value("", v)).collect();
+            res.push('(');
+            res.push_str(&values.join(", "));
+            res.push(')');
+        }
+
+        res
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the set operations combining the queries of a [`UnionQuery`]
+///
+/// [`UnionQuery`]: ./struct.UnionQuery.html
+pub enum SetOperation {
+    UnionAll,
+    Except,
+    ExceptAll,
+    Intersect,
+    IntersectAll,
+}
+
+impl Display for SetOperation {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            SetOperation::UnionAll      => write!(f, "UNION ALL"),
+            SetOperation::Except        => write!(f, "EXCEPT"),
+            SetOperation::ExceptAll     => write!(f, "EXCEPT ALL"),
+            SetOperation::Intersect     => write!(f, "INTERSECT"),
+            SetOperation::IntersectAll  => write!(f, "INTERSECT ALL"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Struct representing several [`SelectQuery`]s combined by a [`SetOperation`]
+///
+/// It is created with [`SelectQuery::union_all_many`], [`SelectQuery::except`] or
+/// [`SelectQuery::intersect`] and rendered for the [`Dialect`] of its first query.
+/// Queries with an ORDER BY, LIMIT or OFFSET are put in parentheses, so these only
+/// apply to the query itself.
+///
+/// The [`KeywordCase`] of the first query wins for the whole statement: the keywords
+/// of every other query are written in it as well, whatever case they were given.
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{SelectQuery, KeywordCase};
+///
+/// let mut first = SelectQuery::select(&["id"]).from("a");
+/// first.keyword_case(KeywordCase::Lower);
+/// let mut second = SelectQuery::select(&["id"]).from("b");
+/// second.keyword_case(KeywordCase::Upper);
+///
+/// assert_eq!(first.except(second).as_string(), "select id from a except select id from b");
+/// ```
+///
+/// [`SelectQuery`]: ./struct.SelectQuery.html
+/// [`KeywordCase`]: ./enum.KeywordCase.html
+/// [`SetOperation`]: ./enum.SetOperation.html
+/// [`SelectQuery::union_all_many`]: ./struct.SelectQuery.html#method.union_all_many
+/// [`SelectQuery::except`]: ./struct.SelectQuery.html#method.except
+/// [`SelectQuery::intersect`]: ./struct.SelectQuery.html#method.intersect
+/// [`Dialect`]: ./enum.Dialect.html
+pub struct UnionQuery<'a> {
+    queries: Vec<SelectQuery<'a>>,
+    op: SetOperation,
+}
+
+impl<'a> Display for UnionQuery<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+impl<'a> UnionQuery<'a> {
+    /// Returns the [`String`] representation of the [`UnionQuery`]
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`UnionQuery`]: ./struct.UnionQuery.html
+    pub fn as_string(&self) -> String {
+        self.render(&mut Context::literal(self.dialect()).rendered_by(self.queries[0].renderer))
+    }
+
+    /// Returns the query with a placeholder for every value, along with
+    /// the values in the order of their placeholders
+    ///
+    /// Works like [`SelectQuery::as_parameterized`].
+    ///
+    /// [`SelectQuery::as_parameterized`]: ./struct.SelectQuery.html#method.as_parameterized
+    pub fn as_parameterized(&self) -> (String, Vec<Value<'a>>) {
+        let mut ctx = Context::placeholders(self.dialect());
+        let sql = self.render(&mut ctx);
+
+        (sql, ctx.into_values())
+    }
+
+    /// Returns the query with a placeholder for every value, along with the values
+    ///
+    /// Works like [`SelectQuery::into_prepared`].
+    ///
+    /// [`SelectQuery::into_prepared`]: ./struct.SelectQuery.html#method.into_prepared
+    pub fn into_prepared(self) -> (String, Vec<Value<'a>>) {
+        self.as_parameterized()
+    }
+
+    /// Returns the [`String`] representation of the [`UnionQuery`], or an error if
+    /// one of the queries uses features the [`Dialect`] does not support
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`UnionQuery`]: ./struct.UnionQuery.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn try_as_string(&self) -> Result<String, QueryError> {
+        let mut ctx = Context::literal(self.dialect()).rendered_by(self.queries[0].renderer);
+        let sql = self.render(&mut ctx);
+
+        ctx.finish(sql)
+    }
+
+    /// Checks whether the [`UnionQuery`] can be rendered for its [`Dialect`]
+    ///
+    /// [`UnionQuery`]: ./struct.UnionQuery.html
+    /// [`Dialect`]: ./enum.Dialect.html
+    pub fn validate(&self) -> Result<(), QueryError> {
+        self.try_as_string().map(|_| ())
+    }
+
+    /// Returns the queries like [`as_string`] along with the [`Warning`]s of all of them
+    ///
+    /// Every warning is reported once, in the order the queries first raise it.
+    ///
+    /// [`as_string`]: #method.as_string
+    /// [`Warning`]: ./enum.Warning.html
+    pub fn render_with_warnings(&self) -> (String, Vec<Warning>) {
+        let mut warnings = Vec::new();
+        for w in self.queries.iter().flat_map(|q| q.render_with_warnings().1) {
+            if !warnings.contains(&w) {
+                warnings.push(w);
+            }
+        }
+        (self.as_string(), warnings)
+    }
+
+    /// Returns every value written into the queries, in the order they appear in them
+    pub fn collect_values(&self) -> Vec<&Value<'a>> {
+        self.queries.iter().flat_map(|q| q.collect_values()).collect()
+    }
+
+    /// Returns the [`Dialect`] of the first query
+    ///
+    /// [`Dialect`]: ./enum.Dialect.html
+    fn dialect(&self) -> Dialect {
+        self.queries[0].dialect
+    }
+
+    /// Renders all queries, passing all values through `ctx`
+    fn render(&self, ctx: &mut Context<'a>) -> String {
+        ctx.choose_case(self.queries[0].keyword_case);
+        match self.op {
+            SetOperation::UnionAll => {},
+            SetOperation::Except => ctx.require("EXCEPT", &[Dialect::Generic, Dialect::Postgres, Dialect::Sqlite, Dialect::SqlServer]),
+            SetOperation::Intersect => ctx.require("INTERSECT", &[Dialect::Generic, Dialect::Postgres, Dialect::Sqlite, Dialect::SqlServer]),
+            SetOperation::ExceptAll => ctx.require("EXCEPT ALL", &[Dialect::Generic, Dialect::Postgres]),
+            SetOperation::IntersectAll => ctx.require("INTERSECT ALL", &[Dialect::Generic, Dialect::Postgres]),
+        }
+
+        let queries: Vec<String> = self.queries.iter().map(|q| {
+            let sql = q.render(ctx);
+            /* an ORDER BY or LIMIT would otherwise apply to the whole combination */
+            if q.order_by.is_none() && q.limit.is_none() && q.offset.is_none() {
+                sql
+            } else if ctx.dialect == Dialect::Sqlite {
+                /* SQLite does not allow parentheses around the queries of a compound select */
+                ctx.case().format("SELECT * FROM ({})", &[&sql])
+            } else {
+                format!("({})", sql)
+            }
+        }).collect();
+        queries.join(&format!(" {} ", ctx.kw(self.op)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing what an [`ExplainQuery`] asks the database for
+///
+/// [`ExplainQuery`]: ./struct.ExplainQuery.html
+pub enum ExplainMode {
+    /// The plan the database would use, rendered as `EXPLAIN`
+    Plain,
+    /// The plan along with the actual timings, which requires running the query
+    ///
+    /// Rendered as `EXPLAIN ANALYZE`, only supported by Postgres and MySQL.
+    Analyze,
+    /// The plan as JSON, rendered as `EXPLAIN (FORMAT JSON)` for Postgres and
+    /// `EXPLAIN FORMAT=JSON` for MySQL, which are the only dialects supporting it
+    Json,
+}
+
+#[derive(Debug, Clone, Copy)]
+/// Struct representing a [`SelectQuery`] wrapped in an `EXPLAIN` statement, see [`SelectQuery::explain`]
+///
+/// [`SelectQuery`]: ./struct.SelectQuery.html
+/// [`SelectQuery::explain`]: ./struct.SelectQuery.html#method.explain
+pub struct ExplainQuery<'q, 'a: 'q> {
+    query: &'q SelectQuery<'a>,
+    mode: ExplainMode,
+}
+
+impl<'q, 'a> Display for ExplainQuery<'q, 'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+impl<'q, 'a> ExplainQuery<'q, 'a> {
+    /// Returns the [`String`] representation of the [`ExplainQuery`]
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`ExplainQuery`]: ./struct.ExplainQuery.html
+    pub fn as_string(&self) -> String {
+        self.render(&mut Context::literal(self.query.dialect).rendered_by(self.query.renderer))
+    }
+
+    /// Returns the query with a placeholder for every value, along with
+    /// the values in the order of their placeholders
+    ///
+    /// Works like [`SelectQuery::as_parameterized`].
+    ///
+    /// [`SelectQuery::as_parameterized`]: ./struct.SelectQuery.html#method.as_parameterized
+    pub fn as_parameterized(&self) -> (String, Vec<Value<'a>>) {
+        let mut ctx = Context::placeholders(self.query.dialect);
+        let sql = self.render(&mut ctx);
+
+        (sql, ctx.into_values())
+    }
+
+    /// Returns the [`String`] representation of the [`ExplainQuery`], or an error if
+    /// the dialect of the query does not support the [`ExplainMode`] or the query itself
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`ExplainQuery`]: ./struct.ExplainQuery.html
+    /// [`ExplainMode`]: ./enum.ExplainMode.html
+    pub fn try_as_string(&self) -> Result<String, QueryError> {
+        let mut ctx = Context::literal(self.query.dialect).rendered_by(self.query.renderer);
+        let sql = self.render(&mut ctx);
+
+        ctx.finish(sql)
+    }
+
+    /// Checks whether the [`ExplainQuery`] can be rendered for the dialect of its query
+    ///
+    /// [`ExplainQuery`]: ./struct.ExplainQuery.html
+    pub fn validate(&self) -> Result<(), QueryError> {
+        self.try_as_string().map(|_| ())
+    }
+
+    /// Renders the wrapped query behind the `EXPLAIN` prefix, passing all values through `ctx`
+    fn render(&self, ctx: &mut Context<'a>) -> String {
+        ctx.choose_case(self.query.keyword_case);
+        let prefix = match (self.mode, ctx.dialect) {
+            (ExplainMode::Plain, _) => {
+                ctx.require("EXPLAIN", &[Dialect::Generic, Dialect::MySql, Dialect::Postgres, Dialect::Sqlite]);
+                "EXPLAIN"
+            },
+            (ExplainMode::Analyze, _) => {
+                ctx.require("EXPLAIN ANALYZE", &[Dialect::MySql, Dialect::Postgres]);
+                "EXPLAIN ANALYZE"
+            },
+            (ExplainMode::Json, Dialect::MySql) => "EXPLAIN FORMAT=JSON",
+            (ExplainMode::Json, _) => {
+                ctx.require("EXPLAIN in JSON", &[Dialect::MySql, Dialect::Postgres]);
+                "EXPLAIN (FORMAT JSON)"
+            },
+        };
+
+        format!("{} {}", ctx.kw(prefix), self.query.render(ctx))
+    }
+}
+
+impl<'q, 'a> ToSql for ExplainQuery<'q, 'a> {
+    fn to_sql(&self) -> String {
+        self.as_string()
+    }
+
+    /* EXPLAIN ANALYZE runs the query, which is still a SELECT */
+    fn is_read_only(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> ToSql for UnionQuery<'a> {
+    fn to_sql(&self) -> String {
+        self.as_string()
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> ToSql for ValuesQuery<'a> {
+    fn to_sql(&self) -> String {
+        self.as_string()
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> ToSql for SelectQuery<'a> {
+    fn to_sql(&self) -> String {
+        self.as_string()
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> ToSql for InsertQuery<'a> {
+    fn to_sql(&self) -> String {
+        self.as_string()
+    }
+}
+
+impl<'a> ToSql for DeleteQuery<'a> {
+    fn to_sql(&self) -> String {
+        self.as_string()
+    }
+}
+
+impl<'a> ToSql for UpdateQuery<'a> {
+    fn to_sql(&self) -> String {
+        self.as_string()
+    }
+}
+
+impl<'a> ToSql for MergeQuery<'a> {
+    fn to_sql(&self) -> String {
+        self.as_string()
+    }
+}
+
+impl<'a> ToSql for CreateTableQuery<'a> {
+    fn to_sql(&self) -> String {
+        self.as_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+/// Enum representing the statements controlling transactions
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{ToSql, TransactionStmt};
+///
+/// assert_eq!(TransactionStmt::Begin.to_sql(), "BEGIN");
+/// assert_eq!(TransactionStmt::Savepoint("before_import").to_sql(), "SAVEPOINT before_import");
+/// ```
+pub enum TransactionStmt<'a> {
+    Begin,
+    Commit,
+    Rollback,
+    /// Marks a point inside the transaction that can be rolled back to
+    Savepoint(&'a str),
+    /// Undoes everything since the given savepoint, keeping the transaction open
+    RollbackTo(&'a str),
+}
+
+impl<'a> Display for TransactionStmt<'a> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            TransactionStmt::Begin          => write!(f, "BEGIN"),
+            TransactionStmt::Commit         => write!(f, "COMMIT"),
+            TransactionStmt::Rollback       => write!(f, "ROLLBACK"),
+            TransactionStmt::Savepoint(s)   => write!(f, "SAVEPOINT {}", s),
+            TransactionStmt::RollbackTo(s)  => write!(f, "ROLLBACK TO SAVEPOINT {}", s),
+        }
+    }
+}
+
+impl<'a> ToSql for TransactionStmt<'a> {
+    fn to_sql(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[derive(Default)]
+/// Struct representing several statements that are run one after another, e.g. as a script
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::{QueryBatch, TransactionStmt, DeleteQuery, InsertQuery, Value};
+///
+/// let mut insert = InsertQuery::into("users");
+/// insert.values.insert("name", Value::Varchar("anna"));
+///
+/// let mut batch = QueryBatch::new();
+/// batch.push(TransactionStmt::Begin);
+/// batch.push(DeleteQuery::from("users"));
+/// batch.push(insert);
+/// batch.push(TransactionStmt::Commit);
+///
+/// assert_eq!(
+///     batch.as_string(),
+///     "BEGIN;\nDELETE FROM users;\nINSERT INTO users(name) VALUES('anna');\nCOMMIT;"
+/// );
+/// ```
+pub struct QueryBatch<'q> {
+    statements: Vec<Box<dyn ToSql + 'q>>,
+}
+
+impl<'q> QueryBatch<'q> {
+    /// Returns a new, empty [`QueryBatch`]
+    ///
+    /// [`QueryBatch`]: ./struct.QueryBatch.html
+    pub fn new() -> QueryBatch<'q> {
+        QueryBatch {
+            statements: Vec::new(),
+        }
+    }
+
+    /// Adds the statement `s` to the end of the batch
+    pub fn push<S: ToSql + 'q>(&mut self, s: S) {
+        self.statements.push(Box::new(s));
+    }
+
+    /// Returns the number of statements in the batch
+    pub fn len(&self) -> usize {
+        self.statements.len()
+    }
+
+    /// Returns whether the batch has no statements
+    pub fn is_empty(&self) -> bool {
+        self.statements.is_empty()
+    }
+
+    /// Returns the statements of the batch, each terminated by a semicolon and on a line of its own
+    pub fn as_string(&self) -> String {
+        let statements: Vec<String> = self.statements.iter()
+            .map(|s| format!("{};", s.to_sql()))
+            .collect();
+
+        statements.join("\n")
+    }
+}
+
+impl<'q> Display for QueryBatch<'q> {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+/// Returns an error for the first of `columns` that equals an earlier one when ignoring case
+fn check_duplicate_columns<'a, I: Iterator<Item = &'a str>>(columns: I) -> Result<(), QueryError> {
+    let mut seen = Vec::new();
+    for c in columns {
+        let lower = c.to_lowercase();
+        if seen.contains(&lower) {
+            return Err(QueryError::DuplicateColumn { column: c.to_string() });
+        }
+        seen.push(lower);
+    }
+    Ok(())
+}
+
+/// Adds `value` to `values`, see `SelectQuery::collect_values`
+fn collect_value<'v, 'a>(value: &'v Value<'a>, values: &mut Vec<&'v Value<'a>>) {
+    match *value {
+        Value::List(ref l) => for v in l {
+            collect_value(v, values);
+        },
+        Value::Subquery(ref q) => values.extend(q.collect_values()),
+        Value::Case(ref c) => c.collect_values(values),
+        Value::Column(_) | Value::Default => {},
+        _ => values.push(value),
+    }
+}
+
+/// Adds the values of all `clauses` to `values`
+fn collect_clauses<'v, 'a: 'v, I>(clauses: I, values: &mut Vec<&'v Value<'a>>)
+    where I: IntoIterator<Item = &'v WhereClause<'a>>
+{
+    /* the value of a bare boolean column is never written */
+    for c in clauses.into_iter().filter(|c| !c.bare) {
+        collect_value(&c.cond, values);
+    }
+}
+
+/// Adds the values of all clauses of `cond` to `values`
+fn collect_conds<'v, 'a>(cond: &'v Cond<'a>, values: &mut Vec<&'v Value<'a>>) {
+    for c in cond.clauses() {
+        collect_clauses(::std::slice::from_ref(c), values);
+    }
+}
+
+/// Sorts `clauses` if they are all combined the same way, see `SelectQuery::canonicalize`
+fn canonicalize(clauses: &mut WhereList) {
+    let entries = &mut clauses.entries;
+    let how = match entries.get(1) {
+        Some(e) => e.0.clone(),
+        None => return,
+    };
+
+    if entries[1..].iter().all(|e| e.0 == how) {
+        /* the first combiner is never rendered, but it takes part in the sorting */
+        for e in entries.iter_mut() {
+            e.0 = how.clone();
+        }
+        entries.sort();
+    }
+}
+
+/// Returns the `ONLY` keyword preceding a table if `only` is set
+fn only(only: bool, ctx: &mut Context) -> String {
+    if only {
+        ctx.require("ONLY table references", &[Dialect::Postgres]);
+        ctx.kw("ONLY ")
+    } else {
+        String::new()
+    }
+}
+
+/// Renders the `PARTITION` clause selecting `partitions`, or nothing if there are none
+fn partition(partitions: &[&str], ctx: &mut Context) -> String {
+    if partitions.is_empty() {
+        return String::new();
+    }
+    ctx.require("PARTITION clauses", &[Dialect::MySql]);
+    ctx.case().format(" PARTITION ({})", &[&partitions.join(", ")])
+}
+
+/// Returns whether the raw SQL `fragment` looks like it could be an injection attempt
+///
+/// Raw fragments, e.g. an [`OrderBy::Expression`] or a [`JoinCondition::On`], are
+/// inserted into the query as they are. This function can be used to check untrusted
+/// fragments before doing so: it flags unbalanced quotes as well as semicolons and
+/// comment markers (`--`, `#`, `/*`, `*/`) outside of quoted strings.
+///
+/// This is a best-effort safety net and not a parser, a fragment that passes the
+/// check is not guaranteed to be safe.
+///
+/// ## Example
+///
+/// ```
+/// use query_builder::contains_suspicious_sql;
+///
+/// assert!(!contains_suspicious_sql("LENGTH(name) DESC"));
+/// assert!(!contains_suspicious_sql("name = 'it''s; fine'"));
+/// assert!(contains_suspicious_sql("1; DROP TABLE users"));
+/// assert!(contains_suspicious_sql("name = 'x' --"));
+/// assert!(contains_suspicious_sql("name = 'x"));
+/// ```
+///
+/// [`OrderBy::Expression`]: ./enum.OrderBy.html#variant.Expression
+/// [`JoinCondition::On`]: ./enum.JoinCondition.html#variant.On
+pub fn contains_suspicious_sql(fragment: &str) -> bool {
+    let mut quote: Option<char> = None;
+    let mut chars = fragment.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match quote {
+            /* doubled quotes are escapes, they toggle twice and keep the string open */
+            Some(q) => if c == q {
+                quote = None;
+            },
+            None => match c {
+                '\'' | '"' | '`' => quote = Some(c),
+                ';' | '#' => return true,
+                '-' if chars.peek() == Some(&'-') => return true,
+                '/' if chars.peek() == Some(&'*') => return true,
+                '*' if chars.peek() == Some(&'/') => return true,
+                _ => {},
+            },
+        }
+    }
+
+    quote.is_some()
+}
+
+/// Creates a [`SelectQuery`] in a single expression
+///
+/// The macro takes the selected rows and the table, optionally followed by any
+/// number of `where` equality conditions (which get AND-combined) and a `limit`.
+/// Conditions accept anything that converts into a [`Value`].
+///
+/// ## Example
+///
+/// ```
+/// #[macro_use]
+/// extern crate query_builder;
+///
+/// # fn main() {
+/// let q = select!(["name", "age"], from "users", where "name" = "ezio", where "age" = 42, limit 1);
+///
+/// assert_eq!(q.as_string(), "SELECT name, age FROM users WHERE name = 'ezio' AND age = 42 LIMIT 1");
+/// # }
+/// ```
+///
+/// [`SelectQuery`]: ./struct.SelectQuery.html
+/// [`Value`]: ./enum.Value.html
+#[macro_export]
+macro_rules! select {
+    ([$($row:expr),*], from $table:expr $(, where $col:tt = $val:expr)* $(, limit $limit:expr)?) => {{
+        #[allow(unused_mut)]
+        let mut q = $crate::SelectQuery::select(&[$($row),*]).from($table);
+        $( q.whre.push($crate::WhereClause::new($col, $crate::Value::from($val))); )*
+        $( q.limit($limit); )?
+        q
+    }};
 }
@@ -0,0 +1,88 @@
+//! Binding query parameters with [`tokio_postgres`]
+//!
+//! This module is only available with the `tokio-postgres` feature. It turns the
+//! values returned by `as_parameterized` into parameters for e.g.
+//! `tokio_postgres::Client::query`:
+//!
+//! ```
+//! # extern crate query_builder;
+//! use query_builder::{SelectQuery, Value, WhereClause, Dialect};
+//! use query_builder::postgres;
+//!
+//! let mut query = SelectQuery::select(&["name"]).from("users");
+//! query.whre.push(WhereClause::new("id", Value::Int(7)));
+//! query.dialect(Dialect::Postgres);
+//!
+//! let (sql, values) = query.as_parameterized();
+//! let params = postgres::params(&values);
+//! /* client.query(sql.as_str(), &postgres::refs(&params)) */
+//! assert_eq!(sql, "SELECT name FROM users WHERE id = $1");
+//! assert_eq!(postgres::refs(&params).len(), 1);
+//! ```
+//!
+//! Postgres has no unsigned or single byte integers, so these are sent as the
+//! next larger signed integer type: `Tinyint`, `UnsignedTinyint` as `SMALLINT`,
+//! `UnsignedSmallint` as `INT` and `UnsignedInt`, `UnsignedBigint` as `BIGINT`.
+//! An `UnsignedBigint` that does not fit into a `BIGINT` can not be bound.
+//!
+//! [`tokio_postgres`]: https://docs.rs/tokio-postgres
+
+use std::error::Error;
+
+use bytes::BytesMut;
+use tokio_postgres::types::{IsNull, ToSql, Type};
+
+use Value;
+
+#[derive(Debug)]
+/// Struct wrapping a [`Value`] so it can be bound as a parameter by tokio_postgres
+///
+/// [`Value`]: ../enum.Value.html
+pub struct PgValue<'v>(&'v Value<'v>);
+
+impl<'v> ToSql for PgValue<'v> {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        match *self.0 {
+            Value::Null => Ok(IsNull::Yes),
+            Value::Varchar(v) => v.to_sql_checked(ty, out),
+            Value::Text(ref t) => (&**t).to_sql_checked(ty, out),
+            Value::Bool(b) => b.to_sql_checked(ty, out),
+            Value::Tinyint(t) => i16::from(t).to_sql_checked(ty, out),
+            Value::UnsignedTinyint(ut) => i16::from(ut).to_sql_checked(ty, out),
+            Value::Smallint(s) => s.to_sql_checked(ty, out),
+            Value::UnsignedSmallint(us) => i32::from(us).to_sql_checked(ty, out),
+            Value::Int(i) => i.to_sql_checked(ty, out),
+            Value::UnsignedInt(ui) => i64::from(ui).to_sql_checked(ty, out),
+            Value::Bigint(bi) => bi.to_sql_checked(ty, out),
+            Value::UnsignedBigint(ubi) => if ubi > i64::MAX as u64 {
+                Err(format!("{} does not fit into a BIGINT", ubi).into())
+            } else {
+                (ubi as i64).to_sql_checked(ty, out)
+            },
+            Value::Double(d) => d.0.to_sql_checked(ty, out),
+            Value::Array(ref a) => params(a).to_sql_checked(ty, out),
+            _ => Err(format!("{} can not be bound as a parameter", self.0).into()),
+        }
+    }
+
+    /* the actual check happens in to_sql, once the variant is known */
+    fn accepts(_: &Type) -> bool {
+        true
+    }
+
+    fn to_sql_checked(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        self.to_sql(ty, out)
+    }
+}
+
+/// Wraps every value of `values` in a [`PgValue`]
+///
+/// [`PgValue`]: ./struct.PgValue.html
+pub fn params<'v>(values: &'v [Value<'v>]) -> Vec<PgValue<'v>> {
+    values.iter().map(PgValue).collect()
+}
+
+/// Returns references to `params` in the form `tokio_postgres::Client::query` expects them
+pub fn refs<'p, 'v>(params: &'p [PgValue<'v>]) -> Vec<&'p (dyn ToSql + Sync)> {
+    params.iter().map(|p| p as &(dyn ToSql + Sync)).collect()
+}
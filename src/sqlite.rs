@@ -0,0 +1,74 @@
+//! Binding query parameters with [`rusqlite`]
+//!
+//! This module is only available with the `rusqlite` feature. It turns the
+//! values returned by `as_parameterized` into [`rusqlite::types::Value`]s which
+//! can be passed to e.g. `rusqlite::Connection::execute` via `rusqlite::params_from_iter`:
+//!
+//! ```
+//! # extern crate query_builder;
+//! # extern crate rusqlite;
+//! use query_builder::{SelectQuery, Value, WhereClause, Dialect};
+//! use query_builder::sqlite;
+//!
+//! # fn main() -> rusqlite::Result<()> {
+//! let conn = rusqlite::Connection::open_in_memory()?;
+//! conn.execute("CREATE TABLE users (id INTEGER, name TEXT)", [])?;
+//! conn.execute("INSERT INTO users VALUES (7, 'anna')", [])?;
+//!
+//! let mut query = SelectQuery::select(&["name"]).from("users");
+//! query.whre.push(WhereClause::new("id", Value::Int(7)));
+//! query.dialect(Dialect::Sqlite);
+//!
+//! let (sql, values) = query.as_parameterized();
+//! let params = sqlite::params(&values)?;
+//! let name: String = conn.query_row(&sql, rusqlite::params_from_iter(params), |row| row.get(0))?;
+//! assert_eq!(name, "anna");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! SQLite stores every integer as a 64 bit signed integer and has no boolean type,
+//! so `Bool` is bound as `0` or `1`. An `UnsignedBigint` that does not fit into an
+//! `i64` can not be bound.
+//!
+//! [`rusqlite`]: https://docs.rs/rusqlite
+//! [`rusqlite::types::Value`]: https://docs.rs/rusqlite/latest/rusqlite/types/enum.Value.html
+
+use rusqlite::types::Value as SqliteValue;
+use rusqlite::{Error, Result};
+
+use Value;
+
+/// Converts a single [`Value`] into the matching `rusqlite::types::Value`
+///
+/// Values which can not be bound as a parameter, e.g. a `Column` or an `Array`,
+/// result in an `Error::ToSqlConversionFailure`.
+///
+/// [`Value`]: ../enum.Value.html
+pub fn to_sqlite(value: &Value) -> Result<SqliteValue> {
+    Ok(match *value {
+        Value::Null => SqliteValue::Null,
+        Value::Varchar(v) => SqliteValue::Text(v.to_string()),
+        Value::Text(ref t) => SqliteValue::Text(t.to_string()),
+        Value::Bool(b) => SqliteValue::Integer(i64::from(b)),
+        Value::Tinyint(t) => SqliteValue::Integer(i64::from(t)),
+        Value::UnsignedTinyint(ut) => SqliteValue::Integer(i64::from(ut)),
+        Value::Smallint(s) => SqliteValue::Integer(i64::from(s)),
+        Value::UnsignedSmallint(us) => SqliteValue::Integer(i64::from(us)),
+        Value::Int(i) => SqliteValue::Integer(i64::from(i)),
+        Value::UnsignedInt(ui) => SqliteValue::Integer(i64::from(ui)),
+        Value::Bigint(bi) => SqliteValue::Integer(bi),
+        Value::UnsignedBigint(ubi) => if ubi > i64::MAX as u64 {
+            return Err(Error::ToSqlConversionFailure(format!("{} does not fit into an INTEGER", ubi).into()));
+        } else {
+            SqliteValue::Integer(ubi as i64)
+        },
+        Value::Double(d) => SqliteValue::Real(d.0),
+        _ => return Err(Error::ToSqlConversionFailure(format!("{} can not be bound as a parameter", value).into())),
+    })
+}
+
+/// Converts every value of `values`, stopping at the first one that can not be bound
+pub fn params(values: &[Value]) -> Result<Vec<SqliteValue>> {
+    values.iter().map(to_sqlite).collect()
+}
@@ -13,7 +13,7 @@ mod tests {
     #[test]
     fn select_simple_where() {
         let mut q = SelectQuery::select(&["user, name"]).from("users");
-        q.whre.push(WhereClause::new("name", Value::Varchar("ezio"), None));
+        q.whre.push(WhereClause::new("name", Value::Varchar("ezio")));
 
         assert_eq!(
             q.as_string(),
@@ -24,7 +24,7 @@ mod tests {
     #[test]
     fn select_simple_where_limt() {
         let mut q = SelectQuery::select(&["user"]).from("users");
-        q.whre.push(WhereClause::new("name", Value::Varchar("connor"), None));
+        q.whre.push(WhereClause::new("name", Value::Varchar("connor")));
         q.limit(42);
 
         assert_eq!(
@@ -53,7 +53,7 @@ mod tests {
     #[test]
     fn delete_simple() {
         let mut q = DeleteQuery::from("users");
-        q.whre.push(WhereClause::new("name", Value::Varchar("george"), None));
+        q.whre.push(WhereClause::new("name", Value::Varchar("george")));
 
         assert_eq!(q.as_string(), "DELETE FROM users WHERE name = 'george'")
     }
@@ -78,7 +78,7 @@ mod tests {
     fn update_simple_where() {
         let mut q = UpdateQuery::update("users");
         q.set.insert("name", Value::Varchar("george"));
-        q.whre.push(WhereClause::new("name", Value::Varchar("steve"), None));
+        q.whre.push(WhereClause::new("name", Value::Varchar("steve")));
 
         assert_eq!(
             q.as_string(),
@@ -90,7 +90,7 @@ mod tests {
     fn update_simple_where_limit() {
         let mut q = UpdateQuery::update("users");
         q.set.insert("name", Value::Varchar("george"));
-        q.whre.push(WhereClause::new("name", Value::Varchar("steve"), None));
+        q.whre.push(WhereClause::new("name", Value::Varchar("steve")));
         q.limit(1);
 
         assert_eq!(
@@ -98,4 +98,2474 @@ mod tests {
             "UPDATE users SET name = 'george' WHERE name = 'steve' LIMIT 1"
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn select_order_by_before_limit() {
+        let mut q = SelectQuery::select(&["user"]).from("users");
+        q.limit(10);
+        q.order_by(OrderBy::Row("name"));
+
+        assert_eq!(q.as_string(), "SELECT user FROM users ORDER BY name LIMIT 10")
+    }
+
+    #[test]
+    fn clause_order_is_identical_across_queries() {
+        let mut s = SelectQuery::select(&["user"]).from("users");
+        s.whre.push(WhereClause::new("name", Value::Varchar("ezio")));
+        s.order_by(OrderBy::Row("age"));
+        s.limit(5);
+
+        let mut d = DeleteQuery::from("users");
+        d.whre.push(WhereClause::new("name", Value::Varchar("ezio")));
+        d.order_by(OrderBy::Row("age"));
+        d.limit(5);
+
+        let mut u = UpdateQuery::update("users");
+        u.set.insert("age", Value::Int(42));
+        u.whre.push(WhereClause::new("name", Value::Varchar("ezio")));
+        u.limit(5);
+
+        let tail = "WHERE name = 'ezio' ORDER BY age LIMIT 5";
+        assert_eq!(s.as_string(), format!("SELECT user FROM users {}", tail));
+        assert_eq!(d.as_string(), format!("DELETE FROM users {}", tail));
+        assert_eq!(
+            u.as_string(),
+            "UPDATE users SET age = 42 WHERE name = 'ezio' LIMIT 5"
+        );
+    }
+
+    #[test]
+    fn select_where_interval() {
+        let mut q = SelectQuery::select(&["id"]).from("sessions");
+        q.whre.push(WhereClause::new("lifetime", Value::Interval("7 days")));
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT id FROM sessions WHERE lifetime = INTERVAL '7 days'"
+        );
+    }
+
+    #[test]
+    fn select_order_by_explicit_asc() {
+        let mut q = SelectQuery::select(&["user"]).from("users");
+        q.order_by(OrderBy::RowAsc("name"));
+
+        assert_eq!(q.as_string(), "SELECT user FROM users ORDER BY name ASC")
+    }
+
+    #[test]
+    fn delete_order_by_explicit_asc() {
+        let mut q = DeleteQuery::from("users");
+        q.order_by(OrderBy::RowAsc("name"));
+        q.limit(1);
+
+        assert_eq!(q.as_string(), "DELETE FROM users ORDER BY name ASC LIMIT 1")
+    }
+
+    #[test]
+    fn select_scope_without_where() {
+        let mut q = SelectQuery::select(&["*"]).from("orders");
+        q.scope("tenant_id", Value::Int(3));
+
+        assert_eq!(q.as_string(), "SELECT * FROM orders WHERE tenant_id = 3")
+    }
+
+    #[test]
+    fn select_scope_survives_cleared_where() {
+        let mut q = SelectQuery::select(&["*"]).from("orders");
+        q.scope("tenant_id", Value::Int(3));
+        q.whre.push(WhereClause::new("id", Value::Int(1)));
+        q.whre.clear();
+
+        assert_eq!(q.as_string(), "SELECT * FROM orders WHERE tenant_id = 3")
+    }
+
+    #[test]
+    fn update_scope_with_where() {
+        let mut q = UpdateQuery::update("orders");
+        q.set.insert("paid", Value::Bool(true));
+        q.scope("tenant_id", Value::Int(3));
+        q.whre.push(WhereClause::new("id", Value::Int(1)));
+
+        assert_eq!(
+            q.as_string(),
+            "UPDATE orders SET paid = TRUE WHERE tenant_id = 3 AND id = 1"
+        )
+    }
+
+    #[test]
+    fn delete_scope_with_or_where() {
+        let mut q = DeleteQuery::from("orders");
+        q.scope("tenant_id", Value::Int(3));
+        q.whre.push(WhereClause::new("id", Value::Int(1)));
+        q.whre.push_or(WhereClause::new("id", Value::Int(2)));
+
+        assert_eq!(
+            q.as_string(),
+            "DELETE FROM orders WHERE tenant_id = 3 AND (id = 1 OR id = 2)"
+        )
+    }
+
+    #[test]
+    fn update_parameter_manifest() {
+        let mut q = UpdateQuery::update("users");
+        q.set.insert("name", Value::Varchar("george"));
+        q.set.insert("age", Value::Int(31));
+        q.whre.push(WhereClause::new("id", Value::Int(7)));
+        q.scope("tenant_id", Value::Int(2));
+
+        let (sql, values) = q.as_parameterized();
+        assert_eq!(
+            sql,
+            "UPDATE users SET name = ?, age = ? WHERE tenant_id = ? AND id = ?"
+        );
+        assert_eq!(
+            values,
+            vec![Value::Varchar("george"), Value::Int(31), Value::Int(2), Value::Int(7)]
+        );
+        assert_eq!(
+            q.parameter_manifest(),
+            vec![
+                ("name".to_string(), Value::Varchar("george")),
+                ("age".to_string(), Value::Int(31)),
+                ("tenant_id".to_string(), Value::Int(2)),
+                ("id".to_string(), Value::Int(7)),
+            ]
+        );
+    }
+
+    #[test]
+    fn where_negate_operators() {
+        let negated = |op| WhereClause::with_operator("age", op, Value::Int(18))
+            .negate()
+            .as_string_no_cond();
+
+        assert_eq!(negated(Operator::Eq), "age != 18");
+        assert_eq!(negated(Operator::NotEq), "age = 18");
+        assert_eq!(negated(Operator::Lt), "age >= 18");
+        assert_eq!(negated(Operator::LtEq), "age > 18");
+        assert_eq!(negated(Operator::Gt), "age <= 18");
+        assert_eq!(negated(Operator::GtEq), "age < 18");
+    }
+
+    #[test]
+    fn where_negate_like_and_in() {
+        let like = WhereClause::with_operator("name", Operator::Like, Value::Varchar("jo%"));
+        assert_eq!(like.negate().as_string_no_cond(), "name NOT LIKE 'jo%'");
+        assert_eq!(like.negate().negate().as_string_no_cond(), "name LIKE 'jo%'");
+
+        let ids = Value::List(vec![Value::Int(1), Value::Int(2), Value::Int(3)]);
+        let within = WhereClause::with_operator("id", Operator::In, ids);
+        assert_eq!(within.negate().as_string(), "id NOT IN (1, 2, 3)");
+    }
+
+    #[test]
+    fn postgres_integer_array() {
+        let mut q = InsertQuery::into("posts");
+        q.values.insert("ids", Value::Array(vec![Value::Int(1), Value::Int(2), Value::Int(3)]));
+        q.dialect(Dialect::Postgres);
+
+        assert_eq!(
+            q.try_as_string(),
+            Ok("INSERT INTO posts(ids) VALUES(ARRAY[1, 2, 3])".to_string())
+        );
+    }
+
+    #[test]
+    fn postgres_text_array() {
+        let mut q = UpdateQuery::update("posts");
+        q.set.insert("tags", Value::Array(vec![Value::Varchar("a"), Value::Varchar("b")]));
+        q.dialect(Dialect::Postgres);
+
+        assert_eq!(
+            q.try_as_string(),
+            Ok("UPDATE posts SET tags = ARRAY['a', 'b']".to_string())
+        );
+    }
+
+    #[test]
+    fn array_unsupported_outside_postgres() {
+        let mut q = SelectQuery::select(&["*"]).from("posts");
+        q.whre.push(WhereClause::new("tags", Value::Array(vec![Value::Int(1)])));
+        q.dialect(Dialect::MySql);
+
+        assert_eq!(
+            q.validate(),
+            Err(QueryError::Unsupported { feature: "ARRAY values", dialect: Dialect::MySql })
+        );
+    }
+
+    #[test]
+    fn update_set_null() {
+        let mut q = UpdateQuery::update("users");
+        q.set.insert("name", Value::Varchar("george"));
+        q.set_null("nickname");
+
+        assert_eq!(
+            q.as_string(),
+            "UPDATE users SET name = 'george', nickname = NULL"
+        )
+    }
+
+    #[test]
+    fn select_implicit_join() {
+        let mut q = SelectQuery::select(&["*"]).from_tables(&["a", "b"]);
+        q.whre.push(WhereClause::new("a.id", Value::Column("b.a_id")));
+        q.whre.push(WhereClause::new("b.kind", Value::Int(2)));
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT * FROM a, b WHERE a.id = b.a_id AND b.kind = 2"
+        );
+        assert_eq!(q.as_parameterized().1, vec![Value::Int(2)]);
+    }
+
+    #[test]
+    fn select_from_aliased_subquery() {
+        let mut sub = SelectQuery::select(&["user_id"]).from("orders").alias("t");
+        sub.whre.push(WhereClause::new("paid", Value::Bool(true)));
+
+        let mut q = SelectQuery::select(&["t.user_id"]).from_subquery(sub);
+        q.whre.push(WhereClause::with_operator("t.user_id", Operator::Gt, Value::Int(10)));
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT t.user_id FROM (SELECT user_id FROM orders WHERE paid = TRUE) AS t WHERE t.user_id > 10"
+        );
+        assert_eq!(q.as_parameterized().1, vec![Value::Bool(true), Value::Int(10)]);
+    }
+
+    #[test]
+    fn select_count_distinct() {
+        let mut q = SelectQuery::select(&[]).from("orders");
+        q.select_aggregate(Aggregate::count("user_id").distinct());
+
+        assert_eq!(q.as_string(), "SELECT COUNT(DISTINCT user_id) FROM orders")
+    }
+
+    #[test]
+    fn select_sum_distinct() {
+        let mut q = SelectQuery::select(&["shop"]).from("orders");
+        q.select_aggregate(Aggregate::sum("amount").distinct());
+        q.select_aggregate(Aggregate::sum("amount"));
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT shop, SUM(DISTINCT amount), SUM(amount) FROM orders"
+        )
+    }
+
+    #[test]
+    fn exclude_deleted_is_appended() {
+        let mut s = SelectQuery::select(&["*"]).from("posts");
+        s.whre.push(WhereClause::new("author", Value::Int(4)));
+        s.exclude_deleted("deleted_at");
+
+        let mut u = UpdateQuery::update("posts");
+        u.set.insert("pinned", Value::Bool(true));
+        u.exclude_deleted("deleted_at");
+
+        let mut d = DeleteQuery::from("posts");
+        d.exclude_deleted("deleted_at");
+
+        assert_eq!(
+            s.as_string(),
+            "SELECT * FROM posts WHERE deleted_at IS NULL AND author = 4"
+        );
+        assert_eq!(
+            s.as_parameterized(),
+            ("SELECT * FROM posts WHERE deleted_at IS NULL AND author = ?".to_string(), vec![Value::Int(4)])
+        );
+        assert_eq!(u.as_string(), "UPDATE posts SET pinned = TRUE WHERE deleted_at IS NULL");
+        assert_eq!(d.as_string(), "DELETE FROM posts WHERE deleted_at IS NULL");
+    }
+
+    #[test]
+    fn where_negate_is_null() {
+        let clause = WhereClause::with_operator("deleted_at", Operator::Is, Value::Null);
+
+        assert_eq!(clause.negate().as_string_no_cond(), "deleted_at IS NOT NULL")
+    }
+
+    #[test]
+    fn select_join_using_single_column() {
+        let mut q = SelectQuery::select(&["*"]).from("users");
+        q.join(Join::inner("orders").using(&["user_id"]));
+
+        assert_eq!(q.as_string(), "SELECT * FROM users INNER JOIN orders USING (user_id)")
+    }
+
+    #[test]
+    fn select_join_using_multiple_columns() {
+        let mut q = SelectQuery::select(&["*"]).from("stock");
+        q.join(Join::left("prices").using(&["shop_id", "item_id"]));
+        q.whre.push(WhereClause::new("shop_id", Value::Int(1)));
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT * FROM stock LEFT JOIN prices USING (shop_id, item_id) WHERE shop_id = 1"
+        )
+    }
+
+    #[test]
+    fn select_macro_simple() {
+        let q = select!(["user"], from "users");
+
+        assert_eq!(q.as_string(), SelectQuery::select(&["user"]).from("users").as_string())
+    }
+
+    #[test]
+    fn select_macro_where_limit() {
+        let q = select!(["a", "b"], from "t", where "x" = 1, where "y" = "z", limit 5);
+
+        let mut manual = SelectQuery::select(&["a", "b"]).from("t");
+        manual.whre.push(WhereClause::new("x", Value::Int(1)));
+        manual.whre.push(WhereClause::new("y", Value::Varchar("z")));
+        manual.limit(5);
+
+        assert_eq!(q.as_string(), manual.as_string());
+        assert_eq!(q.as_string(), "SELECT a, b FROM t WHERE x = 1 AND y = 'z' LIMIT 5");
+    }
+
+    #[test]
+    fn select_macro_limit_without_where() {
+        let q = select!(["*"], from "t", limit 3);
+
+        assert_eq!(q.as_string(), "SELECT * FROM t LIMIT 3")
+    }
+
+    #[test]
+    fn update_set_all() {
+        let mut q = UpdateQuery::update("users");
+        q.set_all(&[
+            ("name", Value::Varchar("george")),
+            ("age", Value::Int(3)),
+            ("active", Value::Bool(false)),
+        ]);
+
+        assert_eq!(
+            q.as_string(),
+            "UPDATE users SET name = 'george', age = 3, active = FALSE"
+        )
+    }
+
+    #[test]
+    fn update_set_correlated_subquery() {
+        let mut total = SelectQuery::select(&[]).from("items");
+        total.select_aggregate(Aggregate::sum("amount"));
+        total.whre.push(WhereClause::new("items.order_id", Value::Column("orders.id")));
+        total.whre.push(WhereClause::new("items.voided", Value::Bool(false)));
+
+        let mut q = UpdateQuery::update("orders");
+        q.set.insert("total", Value::Subquery(Box::new(total)));
+        q.whre.push(WhereClause::new("orders.id", Value::Int(9)));
+
+        assert_eq!(
+            q.as_string(),
+            "UPDATE orders SET total = (SELECT SUM(amount) FROM items WHERE items.order_id = orders.id AND items.voided = FALSE) WHERE orders.id = 9"
+        );
+        assert_eq!(q.as_parameterized().1, vec![Value::Bool(false), Value::Int(9)]);
+    }
+
+    #[test]
+    fn parameterized_postgres_placeholders() {
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.whre.push(WhereClause::new("age", Value::Int(18)));
+        q.whre.push(WhereClause::new("city", Value::Varchar("berlin")));
+        q.whre.push(WhereClause::new("created", Value::Interval("1 day")));
+        q.dialect(Dialect::Postgres);
+
+        let (sql, values) = q.as_parameterized();
+        assert_eq!(sql, "SELECT name FROM users WHERE age = $1 AND city = $2 AND created = INTERVAL '1 day'");
+        assert_eq!(values, vec![Value::Int(18), Value::Varchar("berlin")]);
+    }
+
+    #[test]
+    fn select_paginate() {
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.paginate(1, 20).unwrap();
+        assert_eq!(q.as_string(), "SELECT name FROM users LIMIT 20 OFFSET 0");
+
+        q.paginate(3, 20).unwrap();
+        assert_eq!(q.as_string(), "SELECT name FROM users LIMIT 20 OFFSET 40");
+        assert_eq!(q.get_offset(), Some(40));
+    }
+
+    #[test]
+    fn select_paginate_invalid_pages() {
+        let mut q = SelectQuery::select(&["name"]).from("users");
+
+        assert_eq!(q.paginate(0, 20), Err(QueryError::PageZero));
+        assert_eq!(
+            q.paginate(usize::MAX, 2),
+            Err(QueryError::PageOutOfRange { page: usize::MAX, per_page: 2 })
+        );
+        assert_eq!(q.as_string(), "SELECT name FROM users");
+    }
+
+    #[test]
+    fn select_tablesample_system() {
+        let mut q = SelectQuery::select(&["*"]).from("events");
+        q.tablesample(SampleMethod::System, 10);
+        q.dialect(Dialect::Postgres);
+
+        assert_eq!(q.try_as_string(), Ok("SELECT * FROM events TABLESAMPLE SYSTEM (10)".to_string()));
+
+        q.dialect(Dialect::MySql);
+        assert_eq!(
+            q.validate(),
+            Err(QueryError::Unsupported { feature: "TABLESAMPLE clauses", dialect: Dialect::MySql })
+        );
+    }
+
+    #[test]
+    fn suspicious_sql_fragments() {
+        let suspicious = [
+            "name = 'x'; DELETE FROM users",
+            "1 = 1 -- rest is gone",
+            "1 = 1 # mysql comment",
+            "id /* hidden */ = 1",
+            "id */ = 1",
+            "name = 'unterminated",
+            "name = \"unterminated",
+        ];
+        let benign = [
+            "LENGTH(name) DESC",
+            "users.id = orders.user_id",
+            "name = 'O''Brien'",
+            "note = 'a; b -- c'",
+            "\"weird;column\" = 1",
+            "price - 1 > 0",
+        ];
+
+        for f in suspicious.iter() {
+            assert!(contains_suspicious_sql(f), "{}", f);
+        }
+        for f in benign.iter() {
+            assert!(!contains_suspicious_sql(f), "{}", f);
+        }
+    }
+
+    #[test]
+    fn from_only_postgres() {
+        let mut q = SelectQuery::select(&["*"]).from_tables(&["cities", "countries"]);
+        q.only();
+        q.dialect(Dialect::Postgres);
+        assert_eq!(q.try_as_string(), Ok("SELECT * FROM ONLY cities, ONLY countries".to_string()));
+
+        let mut d = DeleteQuery::from("cities");
+        d.only();
+        d.dialect(Dialect::Sqlite);
+        assert_eq!(
+            d.validate(),
+            Err(QueryError::Unsupported { feature: "ONLY table references", dialect: Dialect::Sqlite })
+        );
+    }
+
+    #[test]
+    fn where_parameterized_empty() {
+        let q = SelectQuery::select(&["name"]).from("users");
+
+        assert_eq!(q.where_parameterized(), (String::new(), vec![]));
+    }
+
+    #[test]
+    fn where_parameterized_multiple_clauses() {
+        let mut q = DeleteQuery::from("users");
+        q.scope("tenant_id", Value::Int(7));
+        q.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+        q.whre.push_or(WhereClause::new("name", Value::Varchar("bert")));
+        q.limit(1);
+
+        assert_eq!(
+            q.where_parameterized(),
+            (
+                "WHERE tenant_id = ? AND (name = ? OR name = ?)".to_string(),
+                vec![Value::Int(7), Value::Varchar("anna"), Value::Varchar("bert")]
+            )
+        );
+    }
+
+    #[test]
+    fn group_by_rollup() {
+        let mut q = SelectQuery::select(&["year", "month"]).from("sales");
+        q.select_aggregate(Aggregate::sum("amount"));
+        q.group_by(GroupBy::Rollup(&["year", "month"]));
+        q.order_by(OrderBy::Row("year"));
+        q.dialect(Dialect::SqlServer);
+
+        assert_eq!(
+            q.try_as_string(),
+            Ok("SELECT year, month, SUM(amount) FROM sales GROUP BY ROLLUP(year, month) ORDER BY year".to_string())
+        );
+
+        q.dialect(Dialect::Sqlite);
+        assert_eq!(
+            q.validate(),
+            Err(QueryError::Unsupported { feature: "ROLLUP groupings", dialect: Dialect::Sqlite })
+        );
+    }
+
+    #[test]
+    fn group_by_cube_and_grouping_sets() {
+        let mut q = SelectQuery::select(&["brand", "size"]).from("items");
+        q.select_aggregate(Aggregate::count("*"));
+        q.group_by(GroupBy::Cube(&["brand", "size"]));
+        q.dialect(Dialect::Postgres);
+
+        assert_eq!(
+            q.try_as_string(),
+            Ok("SELECT brand, size, COUNT(*) FROM items GROUP BY CUBE(brand, size)".to_string())
+        );
+
+        q.group_by(GroupBy::GroupingSets(&[&["brand"], &["size"], &[]]));
+        assert_eq!(
+            q.as_string(),
+            "SELECT brand, size, COUNT(*) FROM items GROUP BY GROUPING SETS((brand), (size), ())"
+        );
+
+        q.dialect(Dialect::MySql);
+        assert!(q.validate().is_err());
+    }
+
+    #[test]
+    fn canonicalize_equivalent_filters() {
+        let mut a = SelectQuery::select(&["*"]).from("users");
+        a.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+        a.whre.push(WhereClause::with_operator("age", Operator::Gt, Value::Int(18)));
+        a.whre.push(WhereClause::new("active", Value::Bool(true)));
+
+        let mut b = SelectQuery::select(&["*"]).from("users");
+        b.whre.push_or(WhereClause::new("active", Value::Bool(true)));
+        b.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+        b.whre.push(WhereClause::with_operator("age", Operator::Gt, Value::Int(18)));
+
+        a.canonicalize();
+        b.canonicalize();
+        assert_eq!(a.as_string(), "SELECT * FROM users WHERE active = TRUE AND age > 18 AND name = 'anna'");
+        assert_eq!(a.as_string(), b.as_string());
+
+        let mut c = DeleteQuery::from("users");
+        c.whre.push(WhereClause::new("name", Value::Varchar("bert")));
+        c.whre.push_or(WhereClause::new("name", Value::Varchar("anna")));
+        c.canonicalize();
+        assert_eq!(c.as_string(), "DELETE FROM users WHERE name = 'anna' OR name = 'bert'");
+    }
+
+    #[test]
+    fn canonicalize_keeps_mixed_combiners() {
+        let mut q = UpdateQuery::update("users");
+        q.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+        q.whre.push_or(WhereClause::new("age", Value::Int(30)));
+        q.whre.push(WhereClause::new("active", Value::Bool(true)));
+
+        let before = q.as_string();
+        q.canonicalize();
+        assert_eq!(q.as_string(), before);
+    }
+
+    #[test]
+    fn create_table_type_modifiers() {
+        let mut q = CreateTableQuery::create("products");
+        q.column("name", ColumnType::Varchar(Some(255)));
+        q.column("price", ColumnType::Decimal { precision: Some(10), scale: Some(2) });
+        q.column("code", ColumnType::Char(None));
+
+        assert_eq!(
+            q.as_string(),
+            "CREATE TABLE products (name VARCHAR(255), price DECIMAL(10, 2), code CHAR)"
+        );
+    }
+
+    #[test]
+    fn rename_table_and_qualified_columns() {
+        let mut q = SelectQuery::select(&["users.name", "orders.total"]).from("users");
+        q.join(Join::inner("orders").using(&["user_id"]));
+        q.whre.push(WhereClause::new("users.active", Value::Bool(true)));
+        q.whre.push(WhereClause::new("orders.user_id", Value::Column("users.id")));
+        q.order_by(OrderBy::RowDesc("users.name"));
+        q.rename_table("users", "tmp_users");
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT tmp_users.name, orders.total FROM tmp_users INNER JOIN orders USING (user_id) \
+             WHERE tmp_users.active = TRUE AND orders.user_id = tmp_users.id ORDER BY tmp_users.name DESC"
+        );
+    }
+
+    #[test]
+    fn rename_table_keeps_similar_names() {
+        let mut q = SelectQuery::select(&["users_archive.name", "username"]).from("users");
+        q.select_aggregate(Aggregate::count("users.id"));
+        q.rename_table("users", "tmp");
+
+        assert_eq!(q.as_string(), "SELECT users_archive.name, username, COUNT(tmp.id) FROM tmp");
+    }
+
+    #[test]
+    fn where_all_subquery() {
+        let mut prices = SelectQuery::select(&["price"]).from("offers");
+        prices.whre.push(WhereClause::new("shop", Value::Varchar("north")));
+
+        let mut q = SelectQuery::select(&["name"]).from("products");
+        q.whre.push(WhereClause::all("price", Operator::Gt, Value::Subquery(Box::new(prices))));
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT name FROM products WHERE price > ALL (SELECT price FROM offers WHERE shop = 'north')"
+        );
+        assert_eq!(q.as_parameterized().1, vec![Value::Varchar("north")]);
+    }
+
+    #[test]
+    fn where_any_array() {
+        let ids = Value::Array(vec![Value::Int(1), Value::Int(2)]);
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.whre.push(WhereClause::any("id", Operator::Eq, ids));
+        q.dialect(Dialect::Postgres);
+
+        assert_eq!(q.try_as_string(), Ok("SELECT name FROM users WHERE id = ANY (ARRAY[1, 2])".to_string()));
+        assert_eq!(q.as_parameterized().0, "SELECT name FROM users WHERE id = ANY ($1)");
+        assert_eq!(q.whre[0].negate().as_string_no_cond(), "id != ALL (ARRAY[1, 2])");
+    }
+
+    #[test]
+    fn select_by_ids() {
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.scope("tenant_id", Value::Int(2));
+        q.by_ids("id", &[Value::Int(3), Value::Int(5), Value::Int(8)]).unwrap();
+
+        assert_eq!(q.as_string(), "SELECT name FROM users WHERE tenant_id = 2 AND id IN (3, 5, 8)");
+        assert_eq!(q.as_parameterized().0, "SELECT name FROM users WHERE tenant_id = ? AND id IN (?, ?, ?)");
+        assert_eq!(q.by_ids("id", &[]), Err(QueryError::EmptyList));
+        assert_eq!(q.whre.len(), 1);
+    }
+
+    #[test]
+    fn where_clause_comment() {
+        let mut q = SelectQuery::select(&["*"]).from("users");
+        q.whre.push(WhereClause::new("tenant_id", Value::Int(4)).comment("tenant filter"));
+        q.whre.push(WhereClause::new("name", Value::Varchar("anna")).comment("search */ DROP TABLE users --"));
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT * FROM users WHERE tenant_id = 4 /* tenant filter */ AND name = 'anna' /* search * / DROP TABLE users -- */"
+        );
+    }
+
+    #[test]
+    fn write_to_reused_buffer() {
+        let mut select = SelectQuery::select(&["name"]).from("users");
+        select.whre.push(WhereClause::new("age", Value::Int(30)));
+        select.limit(5);
+
+        let mut delete = DeleteQuery::from("users");
+        delete.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+
+        let mut update = UpdateQuery::update("users");
+        update.set.insert("active", Value::Bool(false));
+
+        let mut create = CreateTableQuery::create("users");
+        create.column("name", ColumnType::Text);
+
+        let mut buf = String::new();
+        select.write_to(&mut buf).unwrap();
+        assert_eq!(buf, select.as_string());
+
+        buf.clear();
+        delete.write_to(&mut buf).unwrap();
+        assert_eq!(buf, delete.as_string());
+
+        buf.clear();
+        update.write_to(&mut buf).unwrap();
+        assert_eq!(buf, update.as_string());
+
+        buf.clear();
+        create.write_to(&mut buf).unwrap();
+        assert_eq!(buf, "CREATE TABLE users (name TEXT)");
+    }
+
+    #[test]
+    fn select_thousand_where_clauses() {
+        let ids: Vec<i32> = (0..1000).collect();
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        for &id in &ids {
+            q.whre.push_or(WhereClause::new("id", Value::Int(id)));
+        }
+
+        let conditions: Vec<String> = ids.iter().map(|id| format!("id = {}", id)).collect();
+        assert_eq!(
+            q.as_string(),
+            format!("SELECT name FROM users WHERE {}", conditions.join(" OR "))
+        );
+        assert_eq!(q.as_parameterized().1.len(), 1000);
+    }
+
+    #[test]
+    fn borrowed_and_owned_strings() {
+        let borrowed = "anna";
+        let mut q = SelectQuery::select(&["*"]).from("users");
+        q.whre.push(WhereClause::new("name", Value::Varchar(borrowed)));
+        q.whre.push(WhereClause::new("city", format!("{}{}", "ber", "lin").into()));
+        q.whre.push(WhereClause::new("country", Value::Text(borrowed.into())));
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT * FROM users WHERE name = 'anna' AND city = 'berlin' AND country = 'anna'"
+        );
+        assert_eq!(q.as_parameterized().1[1], Value::Text("berlin".to_string().into()));
+    }
+
+    /* one lifetime is enough to hand queries with borrowed and owned parts around */
+    fn active_in<'a>(table: &'a str, region: &'a str, tag: String) -> DeleteQuery<'a> {
+        let mut q = DeleteQuery::from(table);
+        q.whre.push(WhereClause::new("region", Value::Varchar(region)));
+        q.whre.push(WhereClause::new("tag", tag.into()));
+        q
+    }
+
+    #[test]
+    fn single_lifetime_mixes_borrowed_and_owned() {
+        let region = String::from("eu");
+        let q = active_in("sessions", &region, format!("v{}", 2));
+        assert_eq!(q.as_string(), "DELETE FROM sessions WHERE region = 'eu' AND tag = 'v2'");
+
+        let mut u = UpdateQuery::update("sessions");
+        u.set.insert("tag", String::from("v3").into());
+        u.whre.extend(q.whre);
+        assert_eq!(u.as_string(), "UPDATE sessions SET tag = 'v3' WHERE region = 'eu' AND tag = 'v2'");
+    }
+
+    #[test]
+    fn where_clause_reversed() {
+        let mut q = SelectQuery::select(&["*"]).from("users");
+        q.whre.push(WhereClause::new("status", Value::Varchar("active")).reversed());
+        q.whre.push(WhereClause::with_operator("age", Operator::LtEq, Value::Int(65)).reversed());
+        q.whre.push(WhereClause::with_operator("name", Operator::Like, Value::Varchar("a%")).reversed());
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT * FROM users WHERE 'active' = status AND 65 >= age AND name LIKE 'a%'"
+        );
+        assert_eq!(q.as_parameterized().0, "SELECT * FROM users WHERE ? = status AND ? >= age AND name LIKE ?");
+    }
+
+    #[test]
+    fn merge_update_or_insert() {
+        let mut q = MergeQuery::into("stock").using("deliveries AS d").on("stock.item = d.item");
+        q.update.insert("amount", Value::Column("d.amount"));
+        q.update.insert("updated", Value::Bool(true));
+        q.insert.insert("item", Value::Column("d.item"));
+        q.insert.insert("amount", Value::Column("d.amount"));
+        q.dialect(Dialect::SqlServer);
+
+        assert_eq!(
+            q.try_as_string(),
+            Ok("MERGE INTO stock USING deliveries AS d ON stock.item = d.item \
+                WHEN MATCHED THEN UPDATE SET amount = d.amount, updated = 1 \
+                WHEN NOT MATCHED THEN INSERT (amount, item) VALUES (d.amount, d.item)".to_string())
+        );
+        assert_eq!(q.as_parameterized().1, vec![Value::Bool(true)]);
+
+        q.dialect(Dialect::MySql);
+        assert_eq!(
+            q.validate(),
+            Err(QueryError::Unsupported { feature: "MERGE statements", dialect: Dialect::MySql })
+        );
+    }
+
+    #[test]
+    fn select_bound_limit() {
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.whre.push(WhereClause::new("age", Value::Int(30)));
+        q.paginate(2, 25).unwrap();
+        q.bind_limit();
+        q.dialect(Dialect::Postgres);
+
+        let (sql, values) = q.as_parameterized();
+        assert_eq!(sql, "SELECT name FROM users WHERE age = $1 LIMIT $2 OFFSET $3");
+        assert_eq!(values, vec![Value::Int(30), Value::UnsignedBigint(25), Value::UnsignedBigint(25)]);
+        assert_eq!(q.parameter_manifest()[1], ("LIMIT".to_string(), Value::UnsignedBigint(25)));
+    }
+
+    #[test]
+    fn select_count_over() {
+        let mut q = SelectQuery::select(&["id", "name"]).from("users");
+        q.count_over();
+        q.whre.push(WhereClause::new("active", Value::Bool(true)));
+        q.limit(20);
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT id, name, COUNT(*) OVER () AS total_count FROM users WHERE active = TRUE LIMIT 20"
+        );
+    }
+
+    #[test]
+    fn transaction_statements() {
+        assert_eq!(TransactionStmt::Begin.to_sql(), "BEGIN");
+        assert_eq!(TransactionStmt::Commit.to_sql(), "COMMIT");
+        assert_eq!(TransactionStmt::Rollback.to_sql(), "ROLLBACK");
+        assert_eq!(TransactionStmt::Savepoint("sp1").to_sql(), "SAVEPOINT sp1");
+        assert_eq!(TransactionStmt::RollbackTo("sp1").to_sql(), "ROLLBACK TO SAVEPOINT sp1");
+    }
+
+    #[test]
+    fn batch_in_transaction() {
+        let mut update = UpdateQuery::update("accounts");
+        update.set.insert("balance", Value::Int(0));
+        update.whre.push(WhereClause::new("id", Value::Int(1)));
+
+        let mut batch = QueryBatch::new();
+        batch.push(TransactionStmt::Begin);
+        batch.push(update);
+        batch.push(TransactionStmt::Savepoint("after_reset"));
+        batch.push(DeleteQuery::from("logs"));
+        batch.push(TransactionStmt::Commit);
+
+        assert_eq!(batch.len(), 5);
+        assert_eq!(
+            batch.as_string(),
+            "BEGIN;\nUPDATE accounts SET balance = 0 WHERE id = 1;\nSAVEPOINT after_reset;\nDELETE FROM logs;\nCOMMIT;"
+        );
+    }
+
+    #[test]
+    fn duplicate_columns_ignoring_case() {
+        let mut q = UpdateQuery::update("users");
+        q.set.insert("name", Value::Varchar("anna"));
+        q.set.insert("NAME", Value::Varchar("bert"));
+
+        assert_eq!(
+            q.check_duplicate_columns(),
+            Err(QueryError::DuplicateColumn { column: "NAME".to_string() })
+        );
+        assert!(q.validate().is_ok());
+    }
+
+    #[test]
+    fn distinct_columns_pass() {
+        let mut q = InsertQuery::into("users");
+        q.values.insert("name", Value::Varchar("anna"));
+        q.values.insert("nickname", Value::Varchar("an"));
+        q.values.insert("Age", Value::Int(3));
+
+        assert_eq!(q.check_duplicate_columns(), Ok(()));
+    }
+
+    #[test]
+    fn values_two_rows() {
+        let mut v = ValuesQuery::new();
+        v.row(vec![Value::Int(1), Value::Varchar("a")]);
+        v.row(vec![Value::Int(2), Value::Varchar("b")]);
+        assert_eq!(v.as_string(), "VALUES (1, 'a'), (2, 'b')");
+
+        let v = v.alias("codes", &["id", "code"]);
+        let mut q = SelectQuery::select(&["users.name", "codes.code"]).from_values(v);
+        q.join(Join::inner("users").on("users.code_id = codes.id"));
+        q.dialect(Dialect::Postgres);
+
+        let (sql, values) = q.as_parameterized();
+        assert_eq!(
+            sql,
+            "SELECT users.name, codes.code FROM (VALUES ($1, $2), ($3, $4)) AS codes (id, code) INNER JOIN users ON users.code_id = codes.id"
+        );
+        assert_eq!(values.len(), 4);
+    }
+
+    #[test]
+    fn order_by_nulls_last_emulated_on_mysql() {
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.order_by(OrderBy::RowDesc("last_login").nulls_last());
+
+        q.dialect(Dialect::Postgres);
+        assert_eq!(q.try_as_string(), Ok("SELECT name FROM users ORDER BY last_login DESC NULLS LAST".to_string()));
+
+        q.dialect(Dialect::MySql);
+        assert_eq!(
+            q.try_as_string(),
+            Ok("SELECT name FROM users ORDER BY (last_login IS NULL), last_login DESC".to_string())
+        );
+
+        q.order_by(OrderBy::Row("last_login").nulls_first());
+        assert_eq!(q.as_string(), "SELECT name FROM users ORDER BY (last_login IS NOT NULL), last_login");
+
+        q.dialect(Dialect::SqlServer);
+        assert!(q.validate().is_err());
+    }
+
+    #[test]
+    fn update_collect_values() {
+        let mut q = UpdateQuery::update("users");
+        q.set.insert("name", Value::Varchar("anna"));
+        q.set.insert("age", Value::Int(30));
+        q.set.insert("manager", Value::Column("users.boss"));
+        q.scope("tenant_id", Value::Int(7));
+        q.whre.push(WhereClause::with_operator("id", Operator::In, Value::List(vec![Value::Int(1), Value::Int(2)])));
+
+        assert_eq!(
+            q.collect_values(),
+            vec![&Value::Varchar("anna"), &Value::Int(30), &Value::Int(7), &Value::Int(1), &Value::Int(2)]
+        );
+    }
+
+    #[test]
+    fn delete_returning() {
+        let mut q = DeleteQuery::from("sessions");
+        q.whre.push(WhereClause::with_operator("expires", Operator::Lt, Value::Int(100)));
+        q.returning(&["id"]);
+        q.dialect(Dialect::Postgres);
+
+        assert_eq!(q.try_as_string().unwrap(), "DELETE FROM sessions WHERE expires < 100 RETURNING id");
+
+        q.returning(&["id", "user_id", "expires"]);
+        assert_eq!(q.as_string(), "DELETE FROM sessions WHERE expires < 100 RETURNING id, user_id, expires");
+
+        q.dialect(Dialect::MySql);
+        assert!(q.validate().is_err());
+    }
+
+    #[derive(Debug)]
+    struct BitBools;
+
+    impl ValueRenderer for BitBools {
+        fn render(&self, v: &Value) -> String {
+            match *v {
+                Value::Bool(b) => format!("b'{}'", b as u8),
+                _ => Dialect::MySql.render(v),
+            }
+        }
+    }
+
+    #[test]
+    fn custom_value_renderer() {
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.whre.push(WhereClause::new("active", Value::Bool(true)));
+        q.whre.push(WhereClause::new("age", Value::Int(30)));
+        q.dialect(Dialect::MySql);
+        assert_eq!(q.as_string(), "SELECT name FROM users WHERE active = TRUE AND age = 30");
+
+        q.renderer(&BitBools);
+        assert_eq!(q.as_string(), "SELECT name FROM users WHERE active = b'1' AND age = 30");
+
+        /* bound values are not rendered at all */
+        let (sql, values) = q.as_parameterized();
+        assert_eq!(sql, "SELECT name FROM users WHERE active = ? AND age = ?");
+        assert_eq!(values, vec![Value::Bool(true), Value::Int(30)]);
+
+        let mut u = UpdateQuery::update("users");
+        u.set.insert("active", Value::Bool(false));
+        u.dialect(Dialect::SqlServer);
+        assert_eq!(u.as_string(), "UPDATE users SET active = 0");
+    }
+
+    #[test]
+    fn max_length_validation() {
+        let mut q = InsertQuery::into("users");
+        q.max_length("name", 5);
+        q.values.insert("name", Value::Text("émile".to_string().into()));
+        q.values.insert("bio", Value::Varchar("no limit is declared for this column"));
+        assert_eq!(q.try_as_string(), Ok("INSERT INTO users(bio, name) VALUES('no limit is declared for this column', 'émile')".to_string()));
+
+        let mut u = UpdateQuery::update("users");
+        u.max_length("name", 5);
+        u.set.insert("name", Value::Varchar("gregory"));
+        assert_eq!(
+            u.try_as_string(),
+            Err(QueryError::TooLong { column: "name".to_string(), max_length: 5 })
+        );
+
+        u.set.insert("name", Value::Varchar("greg"));
+        assert!(u.validate().is_ok());
+    }
+
+    #[test]
+    fn union_all_many() {
+        let queries: Vec<SelectQuery> = ["users", "admins", "guests"].iter().map(|t| {
+            let mut q = SelectQuery::select(&["name"]).from(t);
+            q.whre.push(WhereClause::new("active", Value::Bool(true)));
+            q
+        }).collect();
+        let union = SelectQuery::union_all_many(queries).unwrap();
+        assert_eq!(
+            union.as_string(),
+            "SELECT name FROM users WHERE active = TRUE \
+             UNION ALL SELECT name FROM admins WHERE active = TRUE \
+             UNION ALL SELECT name FROM guests WHERE active = TRUE"
+        );
+        assert_eq!(union.as_parameterized().1.len(), 3);
+
+        let single = SelectQuery::union_all_many(vec![SelectQuery::select(&["id"]).from("users")]).unwrap();
+        assert_eq!(single.as_string(), "SELECT id FROM users");
+
+        assert_eq!(SelectQuery::union_all_many(Vec::new()).unwrap_err(), QueryError::EmptyUnion);
+    }
+
+    #[test]
+    fn bare_boolean_columns() {
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.whre.push(WhereClause::is_true("active"));
+        q.whre.push(WhereClause::is_false("users.banned"));
+        q.rename_table("users", "u");
+
+        assert_eq!(q.as_string(), "SELECT name FROM u WHERE active AND NOT u.banned");
+        assert_eq!(q.as_parameterized(), ("SELECT name FROM u WHERE active AND NOT u.banned".to_string(), vec![]));
+        assert!(q.collect_values().is_empty());
+
+        assert_eq!(WhereClause::is_false("banned").negate().as_string_no_cond(), "banned");
+    }
+
+    #[test]
+    fn max_conditions_limit() {
+        let ids: Vec<Value> = (0..1000).map(Value::Int).collect();
+
+        let mut q = DeleteQuery::from("users");
+        q.max_conditions(100);
+        q.scope("tenant_id", Value::Int(7));
+        q.whre.push(WhereClause::with_operator("id", Operator::In, Value::List(ids)));
+        assert_eq!(q.try_as_string(), Err(QueryError::TooManyConditions { count: 1001, max: 100 }));
+
+        let mut u = UpdateQuery::update("users");
+        u.max_conditions(2);
+        u.set.insert("active", Value::Bool(false));
+        u.scope("tenant_id", Value::Int(7));
+        u.whre.push(WhereClause::new("id", Value::Int(1)));
+        assert_eq!(u.try_as_string(), Ok("UPDATE users SET active = FALSE WHERE tenant_id = 7 AND id = 1".to_string()));
+    }
+
+    #[test]
+    fn query_hints() {
+        let mut q = SelectQuery::select(&["id", "name"]).from("users");
+        q.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+        q.hint(Hint::UseIndex(&["idx_name", "idx_name_email"]));
+        q.dialect(Dialect::MySql);
+        assert_eq!(
+            q.try_as_string(),
+            Ok("SELECT id, name FROM users USE INDEX (idx_name, idx_name_email) WHERE name = 'anna'".to_string())
+        );
+
+        q.dialect(Dialect::Postgres);
+        assert!(q.validate().is_err());
+
+        let mut q = SelectQuery::select(&["*"]).from("orders");
+        q.hint(Hint::Optimizer("INDEX(orders orders_date_idx) PARALLEL(4)"));
+        assert_eq!(q.try_as_string(), Ok("SELECT /*+ INDEX(orders orders_date_idx) PARALLEL(4) */ * FROM orders".to_string()));
+    }
+
+    #[test]
+    fn read_only_statements() {
+        let select = SelectQuery::select(&["*"]).from("users");
+        assert!(select.is_read_only());
+        assert!(SelectQuery::union_all_many(vec![select.clone(), select]).unwrap().is_read_only());
+        assert!(ValuesQuery::new().is_read_only());
+
+        assert!(!InsertQuery::into("users").is_read_only());
+        assert!(!UpdateQuery::update("users").is_read_only());
+        assert!(!DeleteQuery::from("users").is_read_only());
+        assert!(!MergeQuery::into("users").is_read_only());
+        assert!(!CreateTableQuery::create("users").is_read_only());
+        assert!(!TransactionStmt::Begin.is_read_only());
+    }
+
+    #[test]
+    fn like_with_escape() {
+        let mut q = SelectQuery::select(&["name"]).from("files");
+        q.whre.push(WhereClause::with_operator("name", Operator::Like, Value::Varchar("report\\_%")).escape('\\'));
+        q.whre.push(WhereClause::with_operator("name", Operator::Like, Value::Varchar("%.tmp")).escape('\\').negate());
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT name FROM files WHERE name LIKE 'report\\_%' ESCAPE '\\' AND name NOT LIKE '%.tmp' ESCAPE '\\'"
+        );
+        assert_eq!(
+            q.as_parameterized().0,
+            "SELECT name FROM files WHERE name LIKE ? ESCAPE '\\' AND name NOT LIKE ? ESCAPE '\\'"
+        );
+
+        /* the escape character only applies to LIKE */
+        let clause = WhereClause::new("name", Value::Varchar("a")).escape('!');
+        assert_eq!(clause.as_string_no_cond(), "name = 'a'");
+    }
+
+    #[test]
+    fn create_table_unique_constraints() {
+        let mut q = CreateTableQuery::create("bookings");
+        q.column("id", ColumnType::Bigint);
+        q.column("room", ColumnType::Int);
+        q.column("day", ColumnType::Date);
+        q.unique(&["id"]);
+        q.unique_named("bookings_room_day_key", &["room", "day"]);
+
+        assert_eq!(
+            q.as_string(),
+            "CREATE TABLE bookings (id BIGINT, room INT, day DATE, \
+             UNIQUE (id), CONSTRAINT bookings_room_day_key UNIQUE (room, day))"
+        );
+    }
+
+    #[test]
+    fn where_eq_all_map() {
+        let mut filters = ::std::collections::BTreeMap::new();
+        filters.insert("status", Value::Varchar("open"));
+        filters.insert("assignee", Value::Int(4));
+
+        let mut q = SelectQuery::select(&["id"]).from("tickets");
+        q.whre.push(WhereClause::new("project", Value::Int(1)));
+        q.where_eq_all(filters);
+
+        assert_eq!(q.as_string(), "SELECT id FROM tickets WHERE project = 1 AND assignee = 4 AND status = 'open'");
+    }
+
+    #[test]
+    fn distinct_order_by_check() {
+        let mut q = SelectQuery::select(&["users.city", "country AS land"]).from("users");
+        q.distinct();
+        q.order_by(OrderBy::RowDesc("land"));
+        q.dialect(Dialect::Postgres);
+        assert_eq!(
+            q.try_as_string(),
+            Ok("SELECT DISTINCT users.city, country AS land FROM users ORDER BY land DESC".to_string())
+        );
+
+        q.order_by(OrderBy::Row("users.city").nulls_last());
+        assert!(q.validate().is_ok());
+
+        q.order_by(OrderBy::RowAsc("created"));
+        assert_eq!(q.try_as_string(), Err(QueryError::OrderNotSelected { column: "created".to_string() }));
+
+        let mut all = SelectQuery::select(&["*"]).from("users");
+        all.distinct();
+        all.order_by(OrderBy::Row("created"));
+        assert!(all.validate().is_ok());
+    }
+
+    #[test]
+    fn update_set_keeps_insertion_order() {
+        let mut q = UpdateQuery::update("prices");
+        q.set.insert("previous", Value::Column("current"));
+        q.set.insert("current", Value::Column("proposed"));
+        q.set.insert("changed", Value::Bool(true));
+        /* updating a column keeps its position */
+        q.set.insert("previous", Value::Column("prices.current"));
+        q.rename_table("prices", "p");
+
+        assert_eq!(
+            q.as_string(),
+            "UPDATE p SET previous = p.current, current = proposed, changed = TRUE"
+        );
+        assert_eq!(q.set.remove("changed"), Some(Value::Bool(true)));
+        assert_eq!(q.set.len(), 2);
+    }
+
+    #[test]
+    fn select_from_raw_base() {
+        let mut q = SelectQuery::from_raw("SELECT id, name FROM legacy_users");
+        q.whre.push(WhereClause::new("name", Value::Varchar("anna")));
+        q.order_by(OrderBy::RowDesc("id"));
+        q.limit(10);
+
+        assert_eq!(q.as_string(), "SELECT id, name FROM legacy_users WHERE name = 'anna' ORDER BY id DESC LIMIT 10");
+        assert_eq!(q.as_parameterized().0, "SELECT id, name FROM legacy_users WHERE name = ? ORDER BY id DESC LIMIT 10");
+    }
+
+    #[test]
+    fn where_distinct_from() {
+        let mut q = SelectQuery::select(&["id"]).from("users");
+        q.whre.push(WhereClause::with_operator("deleted_at", Operator::DistinctFrom, Value::Null));
+        q.whre.push(WhereClause::with_operator("manager", Operator::NotDistinctFrom, Value::Int(4)));
+        q.dialect(Dialect::Postgres);
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT id FROM users WHERE deleted_at IS DISTINCT FROM NULL AND manager IS NOT DISTINCT FROM 4"
+        );
+        assert_eq!(
+            q.as_parameterized(),
+            (
+                "SELECT id FROM users WHERE deleted_at IS DISTINCT FROM $1 AND manager IS NOT DISTINCT FROM $2".to_string(),
+                vec![Value::Null, Value::Int(4)]
+            )
+        );
+
+        q.dialect(Dialect::MySql);
+        assert_eq!(
+            q.as_string(),
+            "SELECT id FROM users WHERE NOT (deleted_at <=> NULL) AND manager <=> 4"
+        );
+        assert_eq!(q.whre[1].negate().as_string_no_cond(), "manager IS DISTINCT FROM 4");
+    }
+
+    #[test]
+    fn order_by_nulls_last_expr() {
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.order_by(OrderBy::nulls_last_expr("users.last_login", Direction::Asc));
+        q.rename_table("users", "u");
+
+        for d in [Dialect::Generic, Dialect::MySql, Dialect::Postgres, Dialect::Sqlite].iter() {
+            q.dialect(*d);
+            assert_eq!(
+                q.try_as_string(),
+                Ok("SELECT name FROM u ORDER BY u.last_login IS NULL, u.last_login ASC".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn keyword_case_lower_and_upper() {
+        let mut q = SelectQuery::select(&["name", "ORDER_ID"]).from("users");
+        q.whre.push(WhereClause::with_operator("role", Operator::In, Value::List(vec![Value::Varchar("ADMIN OR USER"), Value::Null])));
+        q.whre.push(WhereClause::new("active", Value::Bool(true)).comment("NOT removed"));
+        q.order_by(OrderBy::RowDesc("name"));
+        q.limit(5);
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT name, ORDER_ID FROM users WHERE role IN ('ADMIN OR USER', NULL) \
+             AND active = TRUE /* NOT removed */ ORDER BY name DESC LIMIT 5"
+        );
+
+        q.keyword_case(KeywordCase::Lower);
+        assert_eq!(
+            q.as_string(),
+            "select name, ORDER_ID from users where role in ('ADMIN OR USER', null) \
+             and active = true /* NOT removed */ order by name desc limit 5"
+        );
+
+        q.keyword_case(KeywordCase::Upper);
+        assert!(q.as_string().starts_with("SELECT name, ORDER_ID FROM users WHERE"));
+
+        let mut d = DeleteQuery::from("logs");
+        d.whre.push(WhereClause::new("note", Value::Varchar("it''s NOT */ done")));
+        d.keyword_case(KeywordCase::Lower);
+        assert_eq!(d.as_string(), "delete from logs where note = 'it''s NOT */ done'");
+    }
+
+    #[test]
+    fn keyword_case_lower_everywhere() {
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.group_by(GroupBy::Columns(&["name"]));
+        q.having(WhereClause::with_operator("n", Operator::Gt, Value::Int(1)));
+        q.having_raw("COUNT(*) > 1");
+        q.keyword_case(KeywordCase::Lower);
+        /* raw SQL is written as it was given */
+        assert_eq!(q.as_string(), "select name from users group by name having n > 1 and (COUNT(*) > 1)");
+
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.dialect(Dialect::SqlServer);
+        q.order_by(OrderBy::Row("name"));
+        q.limit(10);
+        q.offset(20);
+        q.keyword_case(KeywordCase::Lower);
+        assert_eq!(q.as_string(), "select name from users order by name offset 20 rows fetch next 10 rows only");
+
+        let mut q = SelectQuery::select(&[]).from("users");
+        q.select_aggregate(Aggregate::count("*"));
+        q.whre.push(WhereClause::with_operator("age", Operator::In, Value::List(vec![Value::Int(1), Value::Null])));
+        q.keyword_case(KeywordCase::Lower);
+        assert_eq!(q.as_string(), "select count(*) from users where age in (1, null)");
+
+        let mut u = UpdateQuery::update("accounts");
+        u.set_default("quota");
+        u.keyword_case(KeywordCase::Lower);
+        assert_eq!(u.as_string(), "update accounts set quota = default");
+    }
+
+    #[test]
+    fn keyword_case_keeps_identifiers_and_literals() {
+        let mut q = SelectQuery::select(&["DATE", "KEY", "`ORDER`"]).from("`ORDER`");
+        q.whre.push(WhereClause::new("note", Value::Varchar("it's AND x")));
+        q.whre.push(WhereClause::new("TEXT", Value::Bool(true)));
+        q.order_by(OrderBy::Row("DATE"));
+        q.keyword_case(KeywordCase::Lower);
+        assert_eq!(
+            q.as_string(),
+            "select DATE, KEY, `ORDER` from `ORDER` where note = 'it's AND x' and TEXT = true order by DATE"
+        );
+
+        let mut u = UpdateQuery::update("`GROUP`");
+        u.set.insert("SELECT", Value::Null);
+        u.keyword_case(KeywordCase::Lower);
+        assert_eq!(u.as_string(), "update `GROUP` set SELECT = null");
+    }
+
+    #[test]
+    fn delete_returning_all() {
+        let mut q = DeleteQuery::from("users");
+        q.whre.push(WhereClause::new("id", Value::Int(3)));
+        q.returning_all();
+        q.dialect(Dialect::Postgres);
+
+        assert_eq!(q.try_as_string(), Ok("DELETE FROM users WHERE id = 3 RETURNING *".to_string()));
+        assert_eq!(q.as_parameterized().0, "DELETE FROM users WHERE id = $1 RETURNING *");
+    }
+
+    #[test]
+    fn parameterized_in_list_expands() {
+        let ids = Value::List(vec![Value::Int(1), Value::Int(2), Value::Int(3)]);
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.whre.push(WhereClause::with_operator("id", Operator::In, ids));
+
+        let (sql, values) = q.as_parameterized();
+        assert_eq!(sql, "SELECT name FROM users WHERE id IN (?, ?, ?)");
+        assert_eq!(values, vec![Value::Int(1), Value::Int(2), Value::Int(3)]);
+
+        q.dialect(Dialect::Postgres);
+        assert_eq!(q.as_parameterized().0, "SELECT name FROM users WHERE id IN ($1, $2, $3)");
+    }
+
+    #[test]
+    fn select_raw_projection() {
+        let mut q = SelectQuery::select(&["orders.id"]).from("orders");
+        q.select_raw("date_trunc('month', orders.created)");
+        q.rename_table("orders", "o");
+
+        assert_eq!(q.as_string(), "SELECT o.id, date_trunc('month', orders.created) FROM o");
+    }
+
+    #[test]
+    fn sql_server_offset_fetch() {
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.order_by(OrderBy::Row("name"));
+        q.paginate(3, 20).unwrap();
+        q.dialect(Dialect::SqlServer);
+
+        assert_eq!(
+            q.try_as_string(),
+            Ok("SELECT name FROM users ORDER BY name OFFSET 40 ROWS FETCH NEXT 20 ROWS ONLY".to_string())
+        );
+
+        q.bind_limit();
+        assert_eq!(
+            q.as_parameterized(),
+            (
+                "SELECT name FROM users ORDER BY name OFFSET ? ROWS FETCH NEXT ? ROWS ONLY".to_string(),
+                vec![Value::UnsignedBigint(40), Value::UnsignedBigint(20)]
+            )
+        );
+
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.limit(5);
+        q.dialect(Dialect::SqlServer);
+        assert_eq!(q.as_string(), "SELECT name FROM users OFFSET 0 ROWS FETCH NEXT 5 ROWS ONLY");
+        assert_eq!(q.validate(), Err(QueryError::MissingOrderBy));
+    }
+
+    #[test]
+    fn into_prepared_cache_key() {
+        let update = |name, id| {
+            let mut q = UpdateQuery::update("users");
+            q.set.insert("name", Value::Varchar(name));
+            q.whre.push(WhereClause::new("id", Value::Int(id)));
+            q.dialect(Dialect::Postgres);
+            q
+        };
+
+        let (first, first_values) = update("anna", 1).into_prepared();
+        let (second, second_values) = update("bert", 2).into_prepared();
+
+        assert_eq!(first, "UPDATE users SET name = $1 WHERE id = $2");
+        assert_eq!(first, second);
+        assert_eq!(first_values, vec![Value::Varchar("anna"), Value::Int(1)]);
+        assert_eq!(second_values, vec![Value::Varchar("bert"), Value::Int(2)]);
+    }
+
+    #[test]
+    fn structured_having() {
+        let mut q = SelectQuery::select(&["author"]).from("posts");
+        q.select_aggregate(Aggregate::count("*"));
+        q.whre.push(WhereClause::new("published", Value::Bool(true)));
+        q.group_by(GroupBy::Columns(&["author"]));
+        q.having(WhereClause::with_operator("COUNT(*)", Operator::Gt, Value::Int(0)));
+        q.or_having(WhereClause::with_operator("MAX(likes)", Operator::GtEq, Value::Int(10)));
+        q.order_by(OrderBy::Row("author"));
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT author, COUNT(*) FROM posts WHERE published = TRUE GROUP BY author \
+             HAVING COUNT(*) > 0 OR MAX(likes) >= 10 ORDER BY author"
+        );
+        assert_eq!(
+            q.as_parameterized(),
+            (
+                "SELECT author, COUNT(*) FROM posts WHERE published = ? GROUP BY author \
+                 HAVING COUNT(*) > ? OR MAX(likes) >= ? ORDER BY author".to_string(),
+                vec![Value::Bool(true), Value::Int(0), Value::Int(10)]
+            )
+        );
+    }
+
+    #[test]
+    fn join_against_subquery() {
+        let mut recent = SelectQuery::select(&["user_id", "COUNT(*) AS orders"]).from("orders");
+        recent.whre.push(WhereClause::with_operator("created", Operator::Gt, Value::Varchar("2024-01-01")));
+        recent.group_by(GroupBy::Columns(&["user_id"]));
+
+        let mut q = SelectQuery::select(&["users.name", "r.orders"]).from("users");
+        q.join(Join::subquery(JoinType::Inner, recent, "r").on("r.user_id = users.id"));
+        q.whre.push(WhereClause::new("users.active", Value::Bool(true)));
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT users.name, r.orders FROM users INNER JOIN \
+             (SELECT user_id, COUNT(*) AS orders FROM orders WHERE created > '2024-01-01' GROUP BY user_id) AS r \
+             ON r.user_id = users.id WHERE users.active = TRUE"
+        );
+        assert_eq!(q.as_parameterized().1, vec![Value::Varchar("2024-01-01"), Value::Bool(true)]);
+        assert_eq!(q.collect_values(), vec![&Value::Varchar("2024-01-01"), &Value::Bool(true)]);
+    }
+
+    #[test]
+    fn delete_from_partitions() {
+        let mut q = DeleteQuery::from("events");
+        q.partition(&["p2023", "p2024"]);
+        q.whre.push(WhereClause::with_operator("created", Operator::Lt, Value::Varchar("2024-06-01")));
+        q.dialect(Dialect::MySql);
+
+        assert_eq!(q.as_string(), "DELETE FROM events PARTITION (p2023, p2024) WHERE created < '2024-06-01'");
+        assert!(q.validate().is_ok());
+
+        q.dialect(Dialect::Postgres);
+        assert!(q.validate().is_err());
+    }
+
+    #[test]
+    fn dedup_in_list() {
+        let ids = Value::List(vec![Value::Int(5), Value::Int(2), Value::Int(5), Value::Int(7), Value::Int(2)]);
+        let mut q = SelectQuery::select(&["*"]).from("users");
+        q.whre.push(WhereClause::with_operator("id", Operator::In, ids.clone()).dedup());
+
+        assert_eq!(q.as_string(), "SELECT * FROM users WHERE id IN (5, 2, 7)");
+        assert_eq!(q.as_parameterized().1, vec![Value::Int(5), Value::Int(2), Value::Int(7)]);
+
+        /* only lists compared with IN are touched */
+        let clause = WhereClause::with_operator("tags", Operator::Eq, ids).dedup();
+        assert_eq!(clause.as_string_no_cond(), "tags = (5, 2, 5, 7, 2)");
+    }
+
+    struct ProductFilter<'c> {
+        category: Option<&'c str>,
+        in_stock: Option<bool>,
+        max_price: Option<i32>,
+    }
+
+    impl<'c> QueryFilter<'c> for ProductFilter<'c> {
+        fn to_where(&self) -> Vec<WhereClause<'c>> {
+            let mut clauses = Vec::new();
+            if let Some(category) = self.category {
+                clauses.push(WhereClause::new("category", Value::Varchar(category)));
+            }
+            if let Some(in_stock) = self.in_stock {
+                clauses.push(WhereClause::new("in_stock", Value::Bool(in_stock)));
+            }
+            if let Some(max_price) = self.max_price {
+                clauses.push(WhereClause::new("max_price", Value::Int(max_price)));
+            }
+            clauses
+        }
+    }
+
+    #[test]
+    fn filter_from_struct() {
+        let filter = ProductFilter { category: Some("books"), in_stock: None, max_price: Some(20) };
+        let mut q = SelectQuery::select(&["*"]).from("products");
+        q.filter(&filter);
+
+        assert_eq!(q.as_string(), "SELECT * FROM products WHERE category = 'books' AND max_price = 20");
+        assert_eq!(q.as_parameterized().1, vec![Value::Varchar("books"), Value::Int(20)]);
+
+        let empty = ProductFilter { category: None, in_stock: None, max_price: None };
+        let mut q = SelectQuery::select(&["*"]).from("products");
+        q.filter(&empty);
+        assert_eq!(q.as_string(), "SELECT * FROM products");
+    }
+
+    #[test]
+    fn update_set_fragment() {
+        let mut q = UpdateQuery::update("users");
+        q.whre.push(WhereClause::new("id", Value::Int(4)));
+        assert_eq!(q.set_as_string(), "");
+
+        q.set.insert("name", Value::Varchar("anna"));
+        q.set.insert("active", Value::Bool(true));
+        q.set.insert("score", Value::Int(12));
+        assert_eq!(q.set_as_string(), "SET name = 'anna', active = TRUE, score = 12");
+
+        q.dialect(Dialect::SqlServer);
+        assert_eq!(q.set_as_string(), "SET name = 'anna', active = 1, score = 12");
+        assert_eq!(q.as_string(), format!("UPDATE users {} WHERE id = 4", q.set_as_string()));
+    }
+
+    #[test]
+    fn update_to_default() {
+        let mut q = UpdateQuery::update("accounts");
+        q.set.insert("plan", Value::Varchar("pro"));
+        q.set_default("quota");
+        q.whre.push(WhereClause::new("id", Value::Int(9)));
+
+        assert_eq!(q.as_string(), "UPDATE accounts SET plan = 'pro', quota = DEFAULT WHERE id = 9");
+
+        q.dialect(Dialect::Postgres);
+        let (sql, values) = q.as_parameterized();
+        assert_eq!(sql, "UPDATE accounts SET plan = $1, quota = DEFAULT WHERE id = $2");
+        assert_eq!(values, vec![Value::Varchar("pro"), Value::Int(9)]);
+        assert_eq!(q.collect_values(), vec![&Value::Varchar("pro"), &Value::Int(9)]);
+    }
+
+    #[test]
+    fn insert_row_layouts() {
+        let mut q = InsertQuery::into("cities");
+        q.values.insert("name", Value::Varchar("Oslo"));
+        q.values.insert("population", Value::Int(700000));
+        for &(name, population) in [("Bergen", 290000), ("Tromsø", 78000)].iter() {
+            let mut row = ::std::collections::BTreeMap::new();
+            row.insert("name", Value::Varchar(name));
+            row.insert("population", Value::Int(population));
+            q.add_row(row);
+        }
+
+        assert_eq!(
+            q.as_string(),
+            "INSERT INTO cities(name, population) VALUES('Oslo', 700000), ('Bergen', 290000), ('Tromsø', 78000)"
+        );
+
+        q.row_layout(RowLayout::Expanded);
+        assert_eq!(
+            q.as_string(),
+            "INSERT INTO cities(name, population) VALUES\n    \
+             ('Oslo', 700000),\n    \
+             ('Bergen', 290000),\n    \
+             ('Tromsø', 78000)"
+        );
+
+        let (sql, values) = q.as_parameterized();
+        assert_eq!(sql, "INSERT INTO cities(name, population) VALUES\n    (?, ?),\n    (?, ?),\n    (?, ?)");
+        assert_eq!(values.len(), 6);
+        assert_eq!(q.collect_values().len(), 6);
+    }
+
+    #[test]
+    fn insert_row_with_unexpected_column() {
+        let mut q = InsertQuery::into("cities");
+        q.values.insert("name", Value::Varchar("Oslo"));
+
+        let mut row = ::std::collections::BTreeMap::new();
+        row.insert("mayor", Value::Varchar("Anne"));
+        q.add_row(row);
+
+        assert_eq!(q.as_string(), "INSERT INTO cities(name) VALUES('Oslo'), (DEFAULT)");
+        assert_eq!(q.validate(), Err(QueryError::UnexpectedColumn { column: "mayor".to_string() }));
+    }
+
+    #[test]
+    fn order_by_collation() {
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.order_by(OrderBy::RowDesc("name").collate("en_US"));
+        assert_eq!(q.as_string(), "SELECT name FROM users ORDER BY name COLLATE \"en_US\" DESC");
+
+        q.dialect(Dialect::MySql);
+        q.order_by(OrderBy::Row("name").collate("utf8mb4_da_0900_ai_ci"));
+        assert_eq!(q.as_string(), "SELECT name FROM users ORDER BY name COLLATE `utf8mb4_da_0900_ai_ci`");
+
+        q.dialect(Dialect::SqlServer);
+        q.order_by(OrderBy::RowAsc("name").collate("Latin1_General_CI_AS"));
+        assert_eq!(q.as_string(), "SELECT name FROM users ORDER BY name COLLATE Latin1_General_CI_AS ASC");
+    }
+
+    #[test]
+    fn restrict_columns_to_allowlist() {
+        let allowed = ["id", "title", "published"];
+
+        let mut q = SelectQuery::select(&["id", "title"]).from("posts");
+        q.restrict_columns(&allowed);
+        q.order_by(OrderBy::RowDesc("published").nulls_last());
+        assert_eq!(q.try_as_string(), Ok("SELECT id, title FROM posts ORDER BY published DESC NULLS LAST".to_string()));
+
+        let sort = "title; DROP TABLE posts";
+        q.order_by(OrderBy::Expression(sort));
+        assert_eq!(q.try_as_string(), Err(QueryError::UnexpectedColumn { column: sort.to_string() }));
+
+        let mut q = SelectQuery::select(&["id", "author_email"]).from("posts");
+        q.restrict_columns(&allowed);
+        assert_eq!(q.validate(), Err(QueryError::UnexpectedColumn { column: "author_email".to_string() }));
+    }
+
+    #[test]
+    fn table_shorthand() {
+        let mut q = SelectQuery::select(&["*"]).from("users");
+        q.table_shorthand();
+        q.order_by(OrderBy::Row("name"));
+        q.limit(10);
+
+        assert_eq!(q.as_string(), "SELECT * FROM users ORDER BY name LIMIT 10");
+        q.dialect(Dialect::MySql);
+        assert_eq!(q.as_string(), "SELECT * FROM users ORDER BY name LIMIT 10");
+        q.dialect(Dialect::Postgres);
+        assert_eq!(q.as_string(), "TABLE users ORDER BY name LIMIT 10");
+
+        /* conditions need the full statement */
+        q.whre.push(WhereClause::new("active", Value::Bool(true)));
+        assert_eq!(q.as_string(), "SELECT * FROM users WHERE active = TRUE ORDER BY name LIMIT 10");
+
+        let mut q = SelectQuery::select(&["*"]).from("users");
+        q.table_shorthand();
+        q.dialect(Dialect::Postgres);
+        q.where_cond(Cond::new(WhereClause::new("id", Value::Int(1))));
+        assert_eq!(q.as_string(), "SELECT * FROM users WHERE id = 1");
+
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.table_shorthand();
+        q.dialect(Dialect::Postgres);
+        assert_eq!(q.as_string(), "SELECT name FROM users");
+    }
+
+    #[test]
+    fn merge_updates() {
+        let mut q = UpdateQuery::update("orders");
+        q.set.insert("status", Value::Varchar("shipped"));
+        q.whre.push(WhereClause::new("id", Value::Int(12)));
+
+        let mut other = UpdateQuery::update("orders");
+        other.set.insert("status", Value::Varchar("shipped"));
+        other.set.insert("carrier", Value::Varchar("DHL"));
+        other.whre.push(WhereClause::new("archived", Value::Bool(false)));
+
+        assert_eq!(q.merge(other), Ok(()));
+        assert_eq!(
+            q.as_string(),
+            "UPDATE orders SET status = 'shipped', carrier = 'DHL' WHERE id = 12 AND archived = FALSE"
+        );
+
+        /* a condition tree is a filter like any other */
+        let mut q = UpdateQuery::update("users");
+        q.set.insert("a", Value::Int(1));
+        let mut other = UpdateQuery::update("users");
+        other.set.insert("b", Value::Int(2));
+        other.where_cond(Cond::new(WhereClause::new("id", Value::Int(1)))
+            .or(WhereClause::new("id", Value::Int(2))));
+
+        assert_eq!(q.merge(other), Ok(()));
+        assert_eq!(q.as_string(), "UPDATE users SET a = 1, b = 2 WHERE id = 1 OR id = 2");
+    }
+
+    #[test]
+    fn merge_conflicting_updates() {
+        let mut q = UpdateQuery::update("orders");
+        q.set.insert("status", Value::Varchar("shipped"));
+
+        let mut other = UpdateQuery::update("orders");
+        other.set.insert("carrier", Value::Varchar("DHL"));
+        other.set.insert("status", Value::Varchar("cancelled"));
+        assert_eq!(q.merge(other), Err(QueryError::ConflictingAssignment { column: "status".to_string() }));
+        /* nothing of the rejected query is taken over */
+        assert_eq!(q.as_string(), "UPDATE orders SET status = 'shipped'");
+
+        let other = UpdateQuery::update("invoices");
+        assert_eq!(
+            q.merge(other),
+            Err(QueryError::DifferentTables { left: "orders".to_string(), right: "invoices".to_string() })
+        );
+    }
+
+    #[test]
+    fn insert_nullability_annotations() {
+        let mut q = InsertQuery::into("products");
+        q.values.insert("sku", Value::Varchar("A-1"));
+        q.values.insert("note", Value::Null);
+        q.values.insert("price", Value::Int(300));
+        assert_eq!(q.as_string(), "INSERT INTO products(note, price, sku) VALUES(NULL, 300, 'A-1')");
+
+        q.annotate_nullability(&[("sku", Nullability::NotNull), ("note", Nullability::Nullable)]);
+        q.keyword_case(KeywordCase::Lower);
+        assert_eq!(
+            q.as_string(),
+            "insert into products(note /* NULL */, price, sku /* NOT NULL */) values(null, 300, 'A-1')"
+        );
+    }
+
+    #[test]
+    fn join_on_equal_columns() {
+        let mut q = SelectQuery::select(&["users.name", "orders.total"]).from("users");
+        q.join(Join::left("orders").on_eq("users.id", "orders.user_id"));
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT users.name, orders.total FROM users LEFT JOIN orders ON users.id = orders.user_id"
+        );
+
+        q.rename_table("orders", "orders_2024");
+        assert_eq!(
+            q.as_string(),
+            "SELECT users.name, orders_2024.total FROM users LEFT JOIN orders_2024 ON users.id = orders_2024.user_id"
+        );
+    }
+
+    #[test]
+    fn explain_plain() {
+        let mut q = SelectQuery::select(&["id"]).from("users");
+        q.whre.push(WhereClause::new("email", Value::Varchar("a@example.com")));
+
+        assert_eq!(q.explain(ExplainMode::Plain).as_string(), "EXPLAIN SELECT id FROM users WHERE email = 'a@example.com'");
+        assert!(q.explain(ExplainMode::Plain).is_read_only());
+
+        q.dialect(Dialect::Sqlite);
+        let (sql, values) = q.explain(ExplainMode::Plain).as_parameterized();
+        assert_eq!(sql, "EXPLAIN SELECT id FROM users WHERE email = ?");
+        assert_eq!(values, vec![Value::Varchar("a@example.com")]);
+
+        q.dialect(Dialect::SqlServer);
+        assert!(q.explain(ExplainMode::Plain).validate().is_err());
+    }
+
+    #[test]
+    fn explain_analyze() {
+        let mut q = SelectQuery::select(&["id"]).from("users");
+        q.dialect(Dialect::Postgres);
+
+        assert_eq!(q.explain(ExplainMode::Analyze).try_as_string(), Ok("EXPLAIN ANALYZE SELECT id FROM users".to_string()));
+        assert_eq!(q.explain(ExplainMode::Json).as_string(), "EXPLAIN (FORMAT JSON) SELECT id FROM users");
+
+        q.dialect(Dialect::Sqlite);
+        assert_eq!(
+            q.explain(ExplainMode::Analyze).validate(),
+            Err(QueryError::Unsupported { feature: "EXPLAIN ANALYZE", dialect: Dialect::Sqlite })
+        );
+    }
+
+    #[test]
+    fn debug_string_inlines_values() {
+        let mut q = SelectQuery::select(&["*"]).from("books");
+        q.whre.push(WhereClause::new("author", Value::Varchar("Ende")));
+        q.whre.push(WhereClause::with_operator("year", Operator::In, Value::List(vec![Value::Int(1979), Value::Int(1984)])));
+        q.dialect(Dialect::Postgres);
+
+        assert_eq!(q.as_parameterized().0, "SELECT * FROM books WHERE author = $1 AND year IN ($2, $3)");
+        assert_eq!(q.as_debug_string(), q.as_string());
+        assert_eq!(q.as_debug_string(), "SELECT * FROM books WHERE author = 'Ende' AND year IN (1979, 1984)");
+
+        let mut u = UpdateQuery::update("books");
+        u.set.insert("title", Value::Text("Momo's Return".to_string().into()));
+        u.whre.push(WhereClause::new("tags", Value::Array(vec![Value::Varchar("kid's")])));
+        assert_eq!(u.as_debug_string(), "UPDATE books SET title = 'Momo''s Return' WHERE tags = ARRAY['kid''s']");
+
+        /* MySQL also reads backslashes as escapes */
+        let mut q = SelectQuery::select(&["*"]).from("files");
+        q.whre.push(WhereClause::new("path", Value::Varchar("c:\\it's")));
+        q.dialect(Dialect::MySql);
+        assert_eq!(q.as_debug_string(), "SELECT * FROM files WHERE path = 'c:\\\\it''s'");
+        q.dialect(Dialect::Postgres);
+        assert_eq!(q.as_debug_string(), "SELECT * FROM files WHERE path = 'c:\\it''s'");
+    }
+
+    #[test]
+    fn count_distinct_columns_mysql() {
+        let mut q = SelectQuery::select(&["country"]).from("orders");
+        q.select_aggregate(Aggregate::count_distinct(&["customer_id", "product_id"]));
+        q.group_by(GroupBy::Columns(&["country"]));
+        q.dialect(Dialect::MySql);
+
+        assert_eq!(
+            q.try_as_string(),
+            Ok("SELECT country, COUNT(DISTINCT customer_id, product_id) FROM orders GROUP BY country".to_string())
+        );
+    }
+
+    #[test]
+    fn count_distinct_no_columns() {
+        let mut q = SelectQuery::select(&[]).from("orders");
+        q.select_aggregate(Aggregate::count_distinct(&[]));
+        q.dialect(Dialect::MySql);
+
+        assert_eq!(q.validate(), Err(QueryError::EmptyColumns));
+        assert_eq!(q.try_as_string(), Err(QueryError::EmptyColumns));
+    }
+
+    #[test]
+    fn count_distinct_columns_unsupported() {
+        let mut q = SelectQuery::select(&[]).from("orders");
+        q.select_aggregate(Aggregate::count_distinct(&["customer_id", "product_id"]));
+        q.dialect(Dialect::Postgres);
+
+        assert_eq!(
+            q.validate(),
+            Err(QueryError::Unsupported { feature: "COUNT(DISTINCT) of several columns", dialect: Dialect::Postgres })
+        );
+
+        /* a single column is a plain distinct count */
+        let mut q = SelectQuery::select(&[]).from("orders");
+        q.select_aggregate(Aggregate::count_distinct(&["customer_id"]));
+        q.dialect(Dialect::Postgres);
+        assert_eq!(q.try_as_string(), Ok("SELECT COUNT(DISTINCT customer_id) FROM orders".to_string()));
+    }
+
+    #[test]
+    fn where_opt_with_value() {
+        let mut q = SelectQuery::select(&["*"]).from("products");
+        q.where_opt("price", Operator::LtEq, Some(Value::Int(50)), None);
+        q.where_opt("category", Operator::Eq, Some(Value::Varchar("toys")), Some(Condition::Or));
+
+        assert_eq!(q.as_string(), "SELECT * FROM products WHERE price <= 50 OR category = 'toys'");
+    }
+
+    #[test]
+    fn where_opt_without_value() {
+        let mut q = SelectQuery::select(&["*"]).from("products");
+        q.where_opt("price", Operator::LtEq, None, None);
+        assert_eq!(q.as_string(), "SELECT * FROM products");
+        assert!(q.whre.is_empty());
+
+        q.where_opt("category", Operator::Eq, None, None);
+        q.where_opt("stock", Operator::Gt, Some(Value::Int(0)), None);
+        assert_eq!(q.as_string(), "SELECT * FROM products WHERE stock > 0");
+    }
+
+    #[test]
+    fn select_from_function() {
+        let mut q = SelectQuery::select(&["day"]).from_function("generate_series('2024-01-01'::date, '2024-01-07', '1 day')", "day");
+        q.whre.push(WhereClause::with_operator("EXTRACT(DOW FROM day)", Operator::NotIn, Value::List(vec![Value::Int(0), Value::Int(6)])));
+        q.dialect(Dialect::Postgres);
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT day FROM generate_series('2024-01-01'::date, '2024-01-07', '1 day') AS day \
+             WHERE EXTRACT(DOW FROM day) NOT IN (0, 6)"
+        );
+        assert_eq!(q.collect_values(), vec![&Value::Int(0), &Value::Int(6)]);
+    }
+
+    #[test]
+    fn parameters_start_at_index() {
+        let mut q = SelectQuery::select(&["*"]).from("events");
+        q.whre.push(WhereClause::new("kind", Value::Varchar("login")));
+        q.whre.push(WhereClause::with_operator("at", Operator::Gt, Value::Varchar("2024-05-01")));
+        q.dialect(Dialect::Postgres);
+
+        let (sql, values) = q.as_parameterized_from(3);
+        assert_eq!(sql, "SELECT * FROM events WHERE kind = $3 AND at > $4");
+        assert_eq!(values, vec![Value::Varchar("login"), Value::Varchar("2024-05-01")]);
+        assert_eq!(q.as_parameterized_from(1), q.as_parameterized());
+
+        let mut d = DeleteQuery::from("events");
+        d.whre.push(WhereClause::new("kind", Value::Varchar("login")));
+        d.dialect(Dialect::Postgres);
+        assert_eq!(d.as_parameterized_from(3).0, "DELETE FROM events WHERE kind = $3");
+
+        /* question marks are not numbered */
+        d.dialect(Dialect::MySql);
+        assert_eq!(d.as_parameterized_from(3).0, "DELETE FROM events WHERE kind = ?");
+    }
+
+    #[test]
+    fn returning_aliased_expression() {
+        let mut q = DeleteQuery::from("jobs");
+        q.whre.push(WhereClause::new("state", Value::Varchar("done")));
+        q.returning(&["id"]);
+        q.returning_as("jobs.finished_at - jobs.started_at", "runtime");
+        q.dialect(Dialect::Postgres);
+
+        assert_eq!(
+            q.as_string(),
+            "DELETE FROM jobs WHERE state = 'done' RETURNING id, jobs.finished_at - jobs.started_at AS runtime"
+        );
+
+        q.rename_table("jobs", "jobs_archive");
+        assert_eq!(
+            q.as_string(),
+            "DELETE FROM jobs_archive WHERE state = 'done' RETURNING id, jobs.finished_at - jobs.started_at AS runtime"
+        );
+    }
+
+    #[test]
+    fn raw_having_after_group_by() {
+        let mut q = SelectQuery::select(&["customer_id"]).from("orders");
+        q.whre.push(WhereClause::new("status", Value::Varchar("paid")));
+        q.group_by(GroupBy::Columns(&["customer_id"]));
+        q.having_raw("SUM(total) > 1000 OR COUNT(*) > 20");
+        q.order_by(OrderBy::Row("customer_id"));
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT customer_id FROM orders WHERE status = 'paid' GROUP BY customer_id \
+             HAVING SUM(total) > 1000 OR COUNT(*) > 20 ORDER BY customer_id"
+        );
+
+        q.having(WhereClause::with_operator("MIN(total)", Operator::GtEq, Value::Int(5)));
+        assert_eq!(
+            q.as_string(),
+            "SELECT customer_id FROM orders WHERE status = 'paid' GROUP BY customer_id \
+             HAVING MIN(total) >= 5 AND (SUM(total) > 1000 OR COUNT(*) > 20) ORDER BY customer_id"
+        );
+    }
+
+    #[test]
+    fn ordered_limited_update() {
+        let mut q = UpdateQuery::update("jobs");
+        q.set.insert("worker", Value::Varchar("w-7"));
+        q.whre.push(WhereClause::with_operator("worker", Operator::Is, Value::Null));
+        q.order_by(OrderBy::RowAsc("queued_at"));
+        q.limit(10);
+        q.dialect(Dialect::MySql);
+
+        assert_eq!(
+            q.try_as_string(),
+            Ok("UPDATE jobs SET worker = 'w-7' WHERE worker IS NULL ORDER BY queued_at ASC LIMIT 10".to_string())
+        );
+
+        q.dialect(Dialect::Postgres);
+        assert_eq!(q.validate(), Err(QueryError::Unsupported { feature: "ordered updates", dialect: Dialect::Postgres }));
+    }
+
+    #[test]
+    fn insert_in_batches() {
+        let rows = (0..250).map(|i| {
+            let mut row = ::std::collections::BTreeMap::new();
+            row.insert("id", Value::Int(i));
+            row.insert("name", Value::Varchar("sensor"));
+            row
+        });
+
+        let batches: Vec<InsertQuery> = InsertQuery::batches("sensors", rows, 100).dialect(Dialect::Postgres).collect();
+        let sizes: Vec<usize> = batches.iter().map(|q| q.row_count()).collect();
+        assert_eq!(sizes, vec![100, 100, 50]);
+
+        let (sql, values) = batches[2].as_parameterized();
+        assert!(sql.starts_with("INSERT INTO sensors(id, name) VALUES($1, $2), ($3, $4)"));
+        assert!(sql.ends_with("($99, $100)"));
+        assert_eq!(values[0], Value::Int(200));
+        assert_eq!(values.len(), 100);
+    }
+
+    #[test]
+    fn update_with_case() {
+        let case = CaseExpr::new()
+            .when(WhereClause::new("paid", Value::Bool(true)), Value::Varchar("done"))
+            .when(WhereClause::with_operator("due", Operator::Lt, Value::Int(0)), Value::Varchar("late"))
+            .otherwise(Value::Column("status"));
+
+        let mut q = UpdateQuery::update("orders");
+        q.set.insert("status", Value::Case(Box::new(case)));
+        q.whre.push(WhereClause::new("shop", Value::Int(3)));
+
+        assert_eq!(q.as_string(), "UPDATE orders SET status = CASE WHEN paid = TRUE THEN 'done' WHEN due < 0 THEN 'late' ELSE status END WHERE shop = 3");
+
+        q.dialect(Dialect::Postgres);
+        let (sql, values) = q.as_parameterized();
+        assert_eq!(sql, "UPDATE orders SET status = CASE WHEN paid = $1 THEN $2 WHEN due < $3 THEN $4 ELSE status END WHERE shop = $5");
+        assert_eq!(values, vec![Value::Bool(true), Value::Varchar("done"), Value::Int(0), Value::Varchar("late"), Value::Int(3)]);
+        assert_eq!(q.collect_values().len(), 5);
+    }
+
+    #[test]
+    fn single_row_limit() {
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.whre.push(WhereClause::new("active", Value::Bool(true)));
+        assert!(!q.is_single_row());
+
+        q.limit(1);
+        assert!(q.is_single_row());
+
+        q.limit(5);
+        assert!(!q.is_single_row());
+    }
+
+    #[test]
+    fn single_row_heuristics() {
+        let q = SelectQuery::select(&["name"]).from("users");
+        assert!(!q.is_single_row());
+
+        let mut q = SelectQuery::select(&[]).from("users");
+        q.select_aggregate(Aggregate::count("*"));
+        assert!(q.is_single_row());
+
+        let mut q = SelectQuery::select(&["name"]).from("users");
+        q.whre.push(WhereClause::new("users.id", Value::Int(3)));
+        assert!(q.is_single_row());
+
+        /* another branch of an OR may match any number of rows */
+        q.whre.push_or(WhereClause::new("admin", Value::Bool(true)));
+        assert!(!q.is_single_row());
+    }
+
+    #[test]
+    fn full_join_coalesced_key() {
+        let mut q = SelectQuery::select(&["a.total", "b.total"]).from("a");
+        q.full_join_coalesced("b", "a.day", "b.day", "day");
+
+        assert_eq!(
+            q.as_string(),
+            "SELECT a.total, b.total, COALESCE(a.day, b.day) AS day FROM a FULL OUTER JOIN b ON a.day = b.day"
+        );
+
+        q.rename_table("a", "sales_2024");
+        assert_eq!(
+            q.as_string(),
+            "SELECT sales_2024.total, b.total, COALESCE(sales_2024.day, b.day) AS day FROM sales_2024 \
+             FULL OUTER JOIN b ON sales_2024.day = b.day"
+        );
+    }
+
+    #[test]
+    fn doubles_without_exponent() {
+        assert_eq!(Value::from(1e20).as_string(), "100000000000000000000.0");
+        assert_eq!(Value::from(-2.5e21).as_string(), "-2500000000000000000000.0");
+        assert_eq!(Value::from(1e-7).as_string(), "0.0000001");
+        assert_eq!(Value::from(3.0f32).as_string(), "3.0");
+        assert_eq!(Value::from(f64::NAN).as_string(), "'NaN'");
+        assert_eq!(Value::from(f64::NEG_INFINITY).as_string(), "'-Infinity'");
+
+        let mut q = SelectQuery::select(&["id"]).from("stars");
+        q.whre.push(WhereClause::with_operator("mass", Operator::Gt, Value::from(1e30)));
+        assert!(!q.as_string().contains('e'));
+        assert_eq!(q.as_string(), "SELECT id FROM stars WHERE mass > 1000000000000000000000000000000.0");
+    }
+
+    #[test]
+    fn delete_mirroring_select() {
+        let mut select = SelectQuery::select(&["id"]).from("carts");
+        select.dialect(Dialect::Postgres);
+        select.scope("shop", Value::Int(2));
+        select.whre.push(WhereClause::with_operator("updated", Operator::Lt, Value::Varchar("2024-01-01")));
+        select.limit(10);
+
+        let delete = DeleteQuery::from_same_table_as(&select, true).unwrap();
+        let (sql, values) = delete.as_parameterized();
+        assert_eq!(sql, "DELETE FROM carts WHERE shop = $1 AND updated < $2");
+        assert_eq!(values, vec![Value::Int(2), Value::Varchar("2024-01-01")]);
+
+        let delete = DeleteQuery::from_same_table_as(&select, false).unwrap();
+        assert_eq!(delete.as_string(), "DELETE FROM carts");
+
+        let mut select = SelectQuery::select(&["id"]).from("users");
+        select.where_cond(Cond::new(WhereClause::new("id", Value::Int(1)))
+            .or(WhereClause::new("id", Value::Int(2))));
+        let delete = DeleteQuery::from_same_table_as(&select, true).unwrap();
+        assert_eq!(delete.as_string(), "DELETE FROM users WHERE id = 1 OR id = 2");
+
+        select.join(Join::inner("users").on_eq("carts.user_id", "users.id"));
+        assert!(DeleteQuery::from_same_table_as(&select, true).is_none());
+    }
+
+    #[test]
+    fn cond_two_or_groups() {
+        let cond = Cond::group(Cond::new(WhereClause::new("a", Value::Int(1)))
+                .and(WhereClause::new("b", Value::Int(2))))
+            .or(Cond::group(Cond::new(WhereClause::new("c", Value::Int(3)))));
+
+        let mut q = SelectQuery::select(&["*"]).from("t");
+        q.where_cond(cond.clone());
+        assert_eq!(q.as_string(), "SELECT * FROM t WHERE (a = 1 AND b = 2) OR (c = 3)");
+
+        /* other conditions must not bind to a single branch */
+        q.whre.push(WhereClause::new("active", Value::Bool(true)));
+        q.dialect(Dialect::Postgres);
+        let (sql, values) = q.as_parameterized();
+        assert_eq!(sql, "SELECT * FROM t WHERE active = $1 AND ((a = $2 AND b = $3) OR (c = $4))");
+        assert_eq!(values, vec![Value::Bool(true), Value::Int(1), Value::Int(2), Value::Int(3)]);
+        assert_eq!(q.collect_values(), vec![&Value::Bool(true), &Value::Int(1), &Value::Int(2), &Value::Int(3)]);
+
+        let mut d = DeleteQuery::from("t");
+        d.where_cond(Cond::new(WhereClause::new("a", Value::Int(1)))
+            .or(Cond::new(WhereClause::new("b", Value::Int(2))).and(WhereClause::new("c", Value::Int(3)))));
+        assert_eq!(d.as_string(), "DELETE FROM t WHERE a = 1 OR (b = 2 AND c = 3)");
+
+        let mut u = UpdateQuery::update("t");
+        u.set.insert("x", Value::Int(0));
+        u.where_cond(cond);
+        assert_eq!(u.as_string(), "UPDATE t SET x = 0 WHERE (a = 1 AND b = 2) OR (c = 3)");
+    }
+
+    #[test]
+    fn like_patterns_escape_wildcards() {
+        assert_eq!(Value::like_contains("100%"), Value::Text("%100\\%%".into()));
+        assert_eq!(Value::like_starts_with("a_b"), Value::Text("a\\_b%".into()));
+        assert_eq!(Value::like_ends_with("c:\\tmp"), Value::Text("%c:\\\\tmp".into()));
+        assert_eq!(Value::like_contains("plain"), Value::Text("%plain%".into()));
+
+        let mut q = SelectQuery::select(&["id"]).from("coupons");
+        q.dialect(Dialect::Postgres);
+        q.whre.push(WhereClause::with_operator("title", Operator::Like, Value::like_contains("50%")));
+        let (sql, values) = q.as_parameterized();
+        assert_eq!(sql, "SELECT id FROM coupons WHERE title LIKE $1");
+        assert_eq!(values, vec![Value::Text("%50\\%%".into())]);
+    }
+
+    #[test]
+    fn like_clauses_carry_escape() {
+        /* SQLite and SQL Server have no default escape character */
+        for &dialect in &[Dialect::Sqlite, Dialect::SqlServer, Dialect::Postgres] {
+            let mut q = SelectQuery::select(&["id"]).from("coupons");
+            q.dialect(dialect);
+            q.whre.push(WhereClause::contains("title", "50%"));
+            let placeholder = if dialect == Dialect::Postgres { "$1" } else { "?" };
+            assert_eq!(q.as_parameterized().0, format!("SELECT id FROM coupons WHERE title LIKE {} ESCAPE '\\'", placeholder));
+            assert_eq!(q.as_string(), "SELECT id FROM coupons WHERE title LIKE '%50\\%%' ESCAPE '\\'");
+        }
+
+        let mut q = SelectQuery::select(&["id"]).from("files");
+        q.whre.push(WhereClause::starts_with("path", "tmp_"));
+        q.whre.push(WhereClause::ends_with("name", "%.rs"));
+        assert_eq!(
+            q.as_string(),
+            "SELECT id FROM files WHERE path LIKE 'tmp\\_%' ESCAPE '\\' AND name LIKE '%\\%.rs' ESCAPE '\\'"
+        );
+
+        /* inside a MySQL literal the backslash itself has to be escaped */
+        q.dialect(Dialect::MySql);
+        assert!(q.as_string().ends_with("name LIKE '%\\\\%.rs' ESCAPE '\\\\'"));
+
+        /* a backslash of the input is escaped for LIKE, then both are escaped for MySQL */
+        let mut q = SelectQuery::select(&["id"]).from("files");
+        q.whre.push(WhereClause::ends_with("path", "c:\\tmp"));
+        assert_eq!(q.as_string(), "SELECT id FROM files WHERE path LIKE '%c:\\\\tmp' ESCAPE '\\'");
+        q.dialect(Dialect::MySql);
+        assert_eq!(q.as_string(), "SELECT id FROM files WHERE path LIKE '%c:\\\\\\\\tmp' ESCAPE '\\\\'");
+    }
+
+    #[test]
+    fn mixed_combiners_are_grouped() {
+        let mut q = SelectQuery::select(&["*"]).from("t");
+        q.whre.push(WhereClause::new("a", Value::Int(1)));
+        q.whre.push(WhereClause::new("b", Value::Int(2)));
+        q.whre.push_or(WhereClause::new("c", Value::Int(3)));
+        assert_eq!(q.as_string(), "SELECT * FROM t WHERE (a = 1 AND b = 2) OR c = 3");
+
+        q.whre.push(WhereClause::new("d", Value::Int(4)));
+        q.whre.push(WhereClause::new("e", Value::Int(5)));
+        assert_eq!(q.as_string(), "SELECT * FROM t WHERE ((a = 1 AND b = 2) OR c = 3) AND d = 4 AND e = 5");
+
+        /* a single kind of combiner needs no parentheses */
+        let mut q = SelectQuery::select(&["*"]).from("t");
+        q.whre.push_or(WhereClause::new("a", Value::Int(1)));
+        q.whre.push_or(WhereClause::new("b", Value::Int(2)));
+        q.whre.push_or(WhereClause::new("c", Value::Int(3)));
+        assert_eq!(q.as_string(), "SELECT * FROM t WHERE a = 1 OR b = 2 OR c = 3");
+    }
+
+    #[test]
+    fn dry_run_warnings() {
+        let mut q = SelectQuery::select(&["users.*"]).from("users");
+        q.limit(5);
+        let (sql, warnings) = q.render_with_warnings();
+        assert_eq!(sql, q.as_string());
+        assert_eq!(warnings, vec![Warning::UnorderedLimit, Warning::SelectStar]);
+
+        q.order_by(OrderBy::Row("id"));
+        assert_eq!(q.render_with_warnings().1, vec![Warning::SelectStar]);
+
+        let mut q = SelectQuery::select(&["id", "name"]).from("users");
+        q.limit(5);
+        q.order_by(OrderBy::Row("id"));
+        assert!(q.render_with_warnings().1.is_empty());
+
+        let mut u = UpdateQuery::update("users");
+        u.set.insert("active", Value::Bool(false));
+        assert_eq!(u.render_with_warnings(), (u.as_string(), vec![Warning::NoWhere]));
+        u.whre.push(WhereClause::new("id", Value::Int(1)));
+        assert!(u.render_with_warnings().1.is_empty());
+
+        let mut d = DeleteQuery::from("logs");
+        d.limit(100);
+        assert_eq!(d.render_with_warnings().1, vec![Warning::NoWhere, Warning::UnorderedLimit]);
+        assert_eq!(Warning::NoWhere.to_string(), "the query has no WHERE-clause and affects every row");
+    }
+
+    #[test]
+    fn dry_run_warnings_of_other_query_types() {
+        let mut i = InsertQuery::into("users");
+        i.values.insert("name", Value::Varchar("anna"));
+        assert_eq!(i.render_with_warnings(), (i.as_string(), vec![]));
+
+        let m = MergeQuery::into("users").using("staged").on("users.id = staged.id");
+        assert!(m.render_with_warnings().1.is_empty());
+
+        let mut first = SelectQuery::select(&["*"]).from("a");
+        first.limit(1);
+        let mut second = SelectQuery::select(&["*"]).from("b");
+        second.limit(1);
+        let third = SelectQuery::select(&["id"]).from("c");
+        let u = SelectQuery::union_all_many(vec![first, second, third]).unwrap();
+        assert_eq!(u.render_with_warnings(), (u.as_string(), vec![Warning::UnorderedLimit, Warning::SelectStar]));
+    }
+
+    #[test]
+    fn except_two_selects() {
+        let mut active = SelectQuery::select(&["id"]).from("users");
+        active.whre.push(WhereClause::new("active", Value::Bool(true)));
+        let mut banned = SelectQuery::select(&["user_id"]).from("bans");
+        banned.whre.push(WhereClause::new("permanent", Value::Bool(true)));
+
+        let q = active.clone().except(banned.clone());
+        assert_eq!(q.as_string(), "SELECT id FROM users WHERE active = TRUE EXCEPT SELECT user_id FROM bans WHERE permanent = TRUE");
+        assert!(q.validate().is_ok());
+
+        active.dialect(Dialect::Postgres);
+        let (sql, values) = active.clone().except_all(banned.clone()).as_parameterized();
+        assert_eq!(sql, "SELECT id FROM users WHERE active = $1 EXCEPT ALL SELECT user_id FROM bans WHERE permanent = $2");
+        assert_eq!(values, vec![Value::Bool(true), Value::Bool(true)]);
+
+        active.dialect(Dialect::MySql);
+        assert_eq!(
+            active.clone().except(banned.clone()).validate(),
+            Err(QueryError::Unsupported { feature: "EXCEPT", dialect: Dialect::MySql })
+        );
+
+        active.dialect(Dialect::Sqlite);
+        assert!(active.clone().except(banned.clone()).validate().is_ok());
+        assert!(active.except_all(banned).validate().is_err());
+    }
+
+    #[test]
+    fn set_operation_branches_with_limits() {
+        let mut newest = SelectQuery::select(&["id"]).from("posts");
+        newest.order_by(OrderBy::RowDesc("created"));
+        newest.limit(10);
+        let pinned = SelectQuery::select(&["id"]).from("pinned");
+
+        assert_eq!(
+            newest.clone().except(pinned.clone()).as_string(),
+            "(SELECT id FROM posts ORDER BY created DESC LIMIT 10) EXCEPT SELECT id FROM pinned"
+        );
+        assert_eq!(
+            pinned.clone().intersect(newest.clone()).as_string(),
+            "SELECT id FROM pinned INTERSECT (SELECT id FROM posts ORDER BY created DESC LIMIT 10)"
+        );
+
+        let mut sqlite = newest.clone();
+        sqlite.dialect(Dialect::Sqlite);
+        assert_eq!(
+            sqlite.except(pinned.clone()).as_string(),
+            "SELECT * FROM (SELECT id FROM posts ORDER BY created DESC LIMIT 10) EXCEPT SELECT id FROM pinned"
+        );
+    }
+
+    #[test]
+    fn set_operation_keyword_case() {
+        let mut first = SelectQuery::select(&["id"]).from("a");
+        first.keyword_case(KeywordCase::Lower);
+        /* the case of the first query wins, even over one set explicitly */
+        let mut second = SelectQuery::select(&["id"]).from("b");
+        second.keyword_case(KeywordCase::Upper);
+
+        assert_eq!(first.clone().except(second.clone()).as_string(), "select id from a except select id from b");
+        assert_eq!(first.clone().intersect_all(second.clone()).as_string(), "select id from a intersect all select id from b");
+        assert_eq!(
+            SelectQuery::union_all_many(vec![first, second]).unwrap().as_string(),
+            "select id from a union all select id from b"
+        );
+
+        /* the other way around every keyword is upper case */
+        let mut lower = SelectQuery::select(&["id"]).from("b");
+        lower.keyword_case(KeywordCase::Lower);
+        assert_eq!(
+            SelectQuery::select(&["id"]).from("a").intersect(lower).as_string(),
+            "SELECT id FROM a INTERSECT SELECT id FROM b"
+        );
+    }
+
+    #[test]
+    fn intersect_two_selects() {
+        let buyers = SelectQuery::select(&["user_id"]).from("orders");
+        let reviewers = SelectQuery::select(&["user_id"]).from("reviews");
+
+        assert_eq!(
+            buyers.clone().intersect(reviewers.clone()).as_string(),
+            "SELECT user_id FROM orders INTERSECT SELECT user_id FROM reviews"
+        );
+        assert_eq!(
+            buyers.clone().intersect_all(reviewers.clone()).as_string(),
+            "SELECT user_id FROM orders INTERSECT ALL SELECT user_id FROM reviews"
+        );
+
+        let mut buyers = buyers;
+        buyers.dialect(Dialect::MySql);
+        assert_eq!(
+            buyers.intersect(reviewers).try_as_string(),
+            Err(QueryError::Unsupported { feature: "INTERSECT", dialect: Dialect::MySql })
+        );
+    }
+
+    #[test]
+    fn reuse_clause_with_both_combiners() {
+        /* the clause has no combiner of its own, it is given when attaching it */
+        let overdue = WhereClause::with_operator("due", Operator::Lt, Value::Varchar("2024-06-01"));
+
+        let mut strict = SelectQuery::select(&["id"]).from("invoices");
+        strict.and_where(WhereClause::new("open", Value::Bool(true)));
+        strict.and_where(overdue.clone());
+        assert_eq!(strict.as_string(), "SELECT id FROM invoices WHERE open = TRUE AND due < '2024-06-01'");
+
+        let mut loose = DeleteQuery::from("invoices");
+        loose.and_where(WhereClause::new("draft", Value::Bool(true)));
+        loose.or_where(overdue.clone());
+        assert_eq!(loose.as_string(), "DELETE FROM invoices WHERE draft = TRUE OR due < '2024-06-01'");
+
+        let mut u = UpdateQuery::update("invoices");
+        u.set.insert("reminded", Value::Bool(true));
+        u.and_where(overdue);
+        u.and_where(WhereClause::new("open", Value::Bool(true)));
+        assert_eq!(u.as_string(), "UPDATE invoices SET reminded = TRUE WHERE due < '2024-06-01' AND open = TRUE");
+    }
+
+    #[test]
+    fn group_by_ordinals() {
+        let mut q = SelectQuery::select(&["country", "city"]).from("sales");
+        q.select_aggregate(Aggregate::sum("amount"));
+        q.group_by(GroupBy::Columns(&["1", "2"]));
+        assert_eq!(q.as_string(), "SELECT country, city, SUM(amount) FROM sales GROUP BY 1, 2");
+        assert!(q.validate().is_ok());
+
+        q.group_by(GroupBy::Columns(&["0"]));
+        assert_eq!(q.validate(), Err(QueryError::OrdinalOutOfRange { ordinal: 0, columns: 3 }));
+
+        /* the size of the projection is unknown with a star */
+        let mut q = SelectQuery::select(&["*"]).from("sales");
+        q.group_by(GroupBy::Columns(&["5"]));
+        assert!(q.validate().is_ok());
+    }
+
+    #[test]
+    fn group_by_mixed_ordinals_and_columns() {
+        let mut q = SelectQuery::select(&["country", "city"]).from("sales");
+        q.select_aggregate(Aggregate::count("*"));
+        q.group_by(GroupBy::Columns(&["1", "city"]));
+        assert_eq!(q.as_string(), "SELECT country, city, COUNT(*) FROM sales GROUP BY 1, city");
+        assert!(q.validate().is_ok());
+
+        q.dialect(Dialect::Postgres);
+        q.group_by(GroupBy::Rollup(&["country", "4"]));
+        assert_eq!(q.as_string(), "SELECT country, city, COUNT(*) FROM sales GROUP BY ROLLUP(country, 4)");
+        assert_eq!(q.try_as_string(), Err(QueryError::OrdinalOutOfRange { ordinal: 4, columns: 3 }));
+    }
+
+    #[test]
+    fn where_list_keeps_combiners() {
+        let mut q = SelectQuery::select(&["team"]).from("players");
+        q.whre.push_with(Condition::Or, WhereClause::new("active", Value::Bool(true)));
+        q.whre.extend(vec![(Condition::Or, WhereClause::new("captain", Value::Bool(true)))]);
+        q.group_by(GroupBy::Columns(&["team"]));
+        q.having(WhereClause::with_operator("COUNT(*)", Operator::Gt, Value::Int(10)));
+        q.or_having(WhereClause::with_operator("MAX(score)", Operator::Gt, Value::Int(90)));
+
+        assert_eq!(q.whre.len(), 2);
+        assert_eq!(q.whre.iter().map(|e| e.0.clone()).collect::<Vec<_>>(), vec![Condition::Or, Condition::Or]);
+        assert_eq!(
+            q.as_string(),
+            "SELECT team FROM players WHERE active = TRUE OR captain = TRUE GROUP BY team HAVING COUNT(*) > 10 OR MAX(score) > 90"
+        );
+    }
+
+    #[test]
+    fn having_mixed_combiners() {
+        let mut q = SelectQuery::select(&["team"]).from("players");
+        q.group_by(GroupBy::Columns(&["team"]));
+        q.having(WhereClause::new("a", Value::Int(1)));
+        q.having(WhereClause::new("b", Value::Int(2)));
+        q.or_having(WhereClause::new("c", Value::Int(3)));
+        assert_eq!(q.as_string(), "SELECT team FROM players GROUP BY team HAVING (a = 1 AND b = 2) OR c = 3");
+
+        /* the same list renders the same way in WHERE */
+        let mut w = SelectQuery::select(&["team"]).from("players");
+        w.whre.push(WhereClause::new("a", Value::Int(1)));
+        w.whre.push(WhereClause::new("b", Value::Int(2)));
+        w.whre.push_or(WhereClause::new("c", Value::Int(3)));
+        assert_eq!(w.as_string(), "SELECT team FROM players WHERE (a = 1 AND b = 2) OR c = 3");
+    }
+
+    #[test]
+    fn having_raw_after_or_having() {
+        let mut q = SelectQuery::select(&["team"]).from("players");
+        q.group_by(GroupBy::Columns(&["team"]));
+        q.having(WhereClause::with_operator("COUNT(*)", Operator::Gt, Value::Int(1)));
+        q.or_having(WhereClause::with_operator("SUM(x)", Operator::Gt, Value::Int(5)));
+        q.having_raw("MAX(score) > 10");
+        assert_eq!(
+            q.as_string(),
+            "SELECT team FROM players GROUP BY team HAVING (COUNT(*) > 1 OR SUM(x) > 5) AND (MAX(score) > 10)"
+        );
+    }
+
+    #[cfg(feature = "tokio-postgres")]
+    mod postgres {
+        extern crate bytes;
+        extern crate tokio_postgres;
+        use self::bytes::BytesMut;
+        use self::tokio_postgres::types::{IsNull, ToSql, Type};
+        use super::query_builder::*;
+
+        fn encode(value: &dyn ToSql, ty: &Type) -> Option<BytesMut> {
+            let mut out = BytesMut::new();
+            match value.to_sql_checked(ty, &mut out) {
+                Ok(IsNull::No) => Some(out),
+                Ok(IsNull::Yes) => None,
+                Err(e) => panic!("{}", e),
+            }
+        }
+
+        #[test]
+        fn binds_like_native_types() {
+            let values = vec![
+                Value::Int(5),
+                Value::UnsignedTinyint(3),
+                Value::UnsignedInt(7),
+                Value::Varchar("anna"),
+                Value::Null,
+            ];
+            let params = postgres::params(&values);
+
+            assert_eq!(encode(&params[0], &Type::INT4), encode(&5i32, &Type::INT4));
+            assert_eq!(encode(&params[1], &Type::INT2), encode(&3i16, &Type::INT2));
+            assert_eq!(encode(&params[2], &Type::INT8), encode(&7i64, &Type::INT8));
+            assert_eq!(encode(&params[3], &Type::TEXT), encode(&"anna", &Type::TEXT));
+            assert_eq!(encode(&params[4], &Type::INT4), None);
+            assert_eq!(postgres::refs(&params).len(), 5);
+        }
+
+        #[test]
+        fn rejects_unsigned_bigint_overflow() {
+            let values = vec![Value::UnsignedBigint(u64::MAX), Value::UnsignedBigint(1)];
+            let params = postgres::params(&values);
+            let mut out = BytesMut::new();
+
+            assert!(params[0].to_sql_checked(&Type::INT8, &mut out).is_err());
+            assert_eq!(encode(&params[1], &Type::INT8), encode(&1i64, &Type::INT8));
+        }
+    }
+
+    #[cfg(feature = "rusqlite")]
+    mod sqlite {
+        extern crate rusqlite;
+        use self::rusqlite::types::Value as SqliteValue;
+        use super::query_builder::*;
+
+        #[test]
+        fn converts_values() {
+            let values = vec![
+                Value::Null,
+                Value::Varchar("anna"),
+                Value::Bool(true),
+                Value::UnsignedTinyint(3),
+                Value::Smallint(-4),
+                Value::UnsignedInt(u32::MAX),
+                Value::UnsignedBigint(9),
+                Value::from(1.5),
+            ];
+
+            assert_eq!(sqlite::params(&values).unwrap(), vec![
+                SqliteValue::Null,
+                SqliteValue::Text("anna".to_string()),
+                SqliteValue::Integer(1),
+                SqliteValue::Integer(3),
+                SqliteValue::Integer(-4),
+                SqliteValue::Integer(i64::from(u32::MAX)),
+                SqliteValue::Integer(9),
+                SqliteValue::Real(1.5),
+            ]);
+        }
+
+        #[test]
+        fn rejects_unbindable_values() {
+            assert!(sqlite::to_sqlite(&Value::UnsignedBigint(u64::MAX)).is_err());
+            assert!(sqlite::to_sqlite(&Value::Array(vec![Value::Int(1)])).is_err());
+            assert!(sqlite::params(&[Value::Int(1), Value::Column("id")]).is_err());
+        }
+    }
+}